@@ -0,0 +1,130 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A global allocator counting the allocations of each thread,
+/// install it with `#[global_allocator]` to get [`AllocStats`] from [`measure`]
+///
+/// Only allocations on the measuring thread are counted,
+/// memory allocated by threads a task spawns itself is not included.
+pub struct CountingAllocator;
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static CURRENT: Cell<usize> = const { Cell::new(0) };
+    static PEAK: Cell<usize> = const { Cell::new(0) };
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+}
+
+fn record(allocated: usize, freed: usize) {
+    // the thread locals are gone while a thread shuts down, those allocations are not counted
+    _ = CURRENT.try_with(|current| {
+        let now = (current.get() + allocated).saturating_sub(freed);
+        current.set(now);
+        _ = PEAK.try_with(|peak| peak.set(peak.get().max(now)));
+    });
+    if allocated > 0 {
+        _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        _ = ALLOCATED.try_with(|total| total.set(total.get() + allocated));
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ACTIVE.store(true, Ordering::Relaxed);
+        record(layout.size(), 0);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ACTIVE.store(true, Ordering::Relaxed);
+        record(layout.size(), 0);
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record(0, layout.size());
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(new_size, layout.size());
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// Whether a [`CountingAllocator`] is installed as global allocator
+pub fn is_active() -> bool {
+    // the runtime allocates before main, so this is set once the allocator is installed
+    ACTIVE.load(Ordering::Relaxed)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// the most heap memory in use at once, relative to the start of the measurement
+    pub peak: usize,
+    pub allocations: usize,
+    /// the sum of all allocation sizes
+    pub allocated: usize,
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+impl AllocStats {
+    pub fn peak_display(&self) -> String {
+        format_bytes(self.peak)
+    }
+
+    pub fn allocated_display(&self) -> String {
+        format_bytes(self.allocated)
+    }
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "peak {}, {} allocations, {} allocated",
+            self.peak_display(),
+            self.allocations,
+            self.allocated_display()
+        )
+    }
+}
+
+/// Run `f` and count its allocations on the current thread,
+/// the stats are `None` when no [`CountingAllocator`] is installed
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !is_active() {
+        return (f(), None);
+    }
+    let start = CURRENT.get();
+    PEAK.set(start);
+    let allocations = ALLOCATIONS.get();
+    let allocated = ALLOCATED.get();
+
+    let result = f();
+
+    let stats = AllocStats {
+        peak: PEAK.get().saturating_sub(start),
+        allocations: ALLOCATIONS.get() - allocations,
+        allocated: ALLOCATED.get() - allocated,
+    };
+    (result, Some(stats))
+}
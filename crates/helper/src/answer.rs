@@ -0,0 +1,51 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+    /// a multi-line answer, e.g. letters drawn on a grid
+    Art(String),
+    /// the task only has side effects, like printing debug output
+    Unit,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+            Answer::Art(value) => write!(f, "{}", value.trim_end()),
+            Answer::Unit => Ok(()),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($variant:ident($target:ty): $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Unsigned(u128): u8, u16, u32, u64, u128, usize);
+impl_from_int!(Signed(i128): i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
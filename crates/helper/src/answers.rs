@@ -0,0 +1,263 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use crate::input::InputError;
+use crate::{Answer, TASKS, Task};
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, std::io::Error),
+    Parse(PathBuf, String),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Io(path, err) => write!(f, "failed to read {}: {err}", path.display()),
+            AnswersError::Parse(path, err) => {
+                write!(f, "failed to parse {}: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// The known answers of one year, stored as
+///
+/// ```toml
+/// [day01]
+/// part1 = 1234
+/// part2 = "text"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<(u8, String), String>,
+}
+
+pub fn answers_path(inputs_root: &Path, year: u16) -> PathBuf {
+    crate::input::answers_dir(inputs_root).join(format!("year{year}.toml"))
+}
+
+impl Answers {
+    /// Load the answers for a year, a missing answers file is treated as having no known answers
+    pub fn load(inputs_root: &Path, year: u16) -> Result<Self, AnswersError> {
+        let path = answers_path(inputs_root, year);
+        match std::fs::read_to_string(&path) {
+            Ok(content) => Self::parse(&content).map_err(|err| AnswersError::Parse(path, err)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(AnswersError::Io(path, err)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let table = content
+            .parse::<toml::Table>()
+            .map_err(|err| err.to_string())?;
+
+        let mut answers = BTreeMap::new();
+        for (day_key, parts) in table {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("{day_key} is not a valid day key, expected e.g. day01"))?;
+            let toml::Value::Table(parts) = parts else {
+                return Err(format!("{day_key} is not a table"));
+            };
+            for (part, value) in parts {
+                let value = answer_value(value).ok_or_else(|| {
+                    format!("{day_key}.{part} is neither an integer nor a string")
+                })?;
+                answers.insert((day, part), value);
+            }
+        }
+
+        Ok(Self { answers })
+    }
+
+    pub fn expected(&self, day: u8, part: &str) -> Option<&str> {
+        self.answers
+            .get(&(day, part.to_owned()))
+            .map(String::as_str)
+    }
+
+    pub fn expected_for(&self, task: &Task) -> Option<&str> {
+        let (_, day) = task.year_day()?;
+        self.expected(day, task.path.last()?)
+    }
+}
+
+/// Add a known answer to the answers file of a year, creating it if necessary,
+/// the line is inserted into the day's table to keep the rest of the file as is
+pub fn record_answer(
+    inputs_root: &Path,
+    year: u16,
+    day: u8,
+    part: &str,
+    answer: &str,
+) -> std::io::Result<()> {
+    let path = answers_path(inputs_root, year);
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err),
+    };
+    let value = match answer.parse::<i64>() {
+        Ok(answer) => answer.to_string(),
+        Err(_) => toml::Value::String(answer.to_owned()).to_string(),
+    };
+    let line = format!("{part} = {value}\n");
+
+    let header = format!("[day{day:02}]");
+    let mut offset = 0;
+    let mut header_end = None;
+    for content_line in content.split_inclusive('\n') {
+        offset += content_line.len();
+        if content_line.trim() == header {
+            header_end = Some(offset);
+            break;
+        }
+    }
+    let updated = match header_end {
+        Some(end) if content[..end].ends_with('\n') => {
+            format!("{}{line}{}", &content[..end], &content[end..])
+        }
+        Some(_) => format!("{content}\n{line}"),
+        None => {
+            let separator = match content.as_str() {
+                "" => "",
+                content if content.ends_with('\n') => "\n",
+                _ => "\n\n",
+            };
+            format!("{content}{separator}{header}\n{line}")
+        }
+    };
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, updated)
+}
+
+pub(crate) fn answer_value(value: toml::Value) -> Option<String> {
+    match value {
+        toml::Value::Integer(value) => Some(value.to_string()),
+        toml::Value::String(value) => Some(value),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    Unknown,
+}
+
+pub fn check(expected: Option<&str>, answer: &Answer) -> Verdict {
+    let Some(expected) = expected else {
+        return Verdict::Unknown;
+    };
+    let actual = answer.to_string();
+    if actual.trim_end() == expected.trim_end() {
+        Verdict::Pass
+    } else {
+        Verdict::Fail {
+            expected: expected.to_owned(),
+            actual,
+        }
+    }
+}
+
+/// Check the registered tasks of a year that have a known answer and are selected,
+/// tasks without a personal input are skipped
+pub fn assert_year_answers(year: u16, selected: impl Fn(&Task) -> bool) {
+    let inputs_root = crate::input::inputs_root();
+    let answers = Answers::load(&inputs_root, year).unwrap();
+
+    let mut failures = vec![];
+    for task in TASKS.iter().filter(|task| {
+        task.year_day()
+            .is_some_and(|(task_year, _)| task_year == year)
+            && selected(task)
+    }) {
+        let Some(expected) = answers.expected_for(task) else {
+            continue;
+        };
+        let input = match task.load_input(&inputs_root) {
+            Ok(input) => input,
+            Err(err @ InputError::NotFound(_)) => {
+                eprintln!("skipping {}: {err}", task.path.join(" "));
+                continue;
+            }
+            Err(err) => panic!("{err}"),
+        };
+        if let Verdict::Fail { expected, actual } = check(Some(expected), &task.solve(&input)) {
+            failures.push(format!(
+                "{}: expected {expected}, got {actual}",
+                task.path.join(" ")
+            ));
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Record the answers into an answers file with the content, returning the updated content
+    fn record(name: &str, content: Option<&str>, answers: &[(u8, &str, &str)]) -> String {
+        let root =
+            std::env::temp_dir().join(format!("helper-answers-{name}-{}", std::process::id()));
+        let path = answers_path(&root, 2023);
+        if let Some(content) = content {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, content).unwrap();
+        }
+        for (day, part, answer) in answers {
+            record_answer(&root, 2023, *day, part, answer).unwrap();
+        }
+        let updated = std::fs::read_to_string(&path);
+        std::fs::remove_dir_all(&root).unwrap();
+        updated.unwrap()
+    }
+
+    #[test]
+    fn new_file() {
+        let content = record("new", None, &[(1, "part1", "42"), (1, "part2", "abc")]);
+        assert_eq!(content, "[day01]\npart2 = \"abc\"\npart1 = 42\n");
+        let answers = Answers::parse(&content).unwrap();
+        assert_eq!(answers.expected(1, "part1"), Some("42"));
+        assert_eq!(answers.expected(1, "part2"), Some("abc"));
+    }
+
+    #[test]
+    fn existing_table() {
+        let content = record(
+            "existing",
+            Some("# comment\n[day01]\npart1 = 1\n\n[day02]\npart1 = 2\n"),
+            &[(1, "part2", "11")],
+        );
+        assert_eq!(
+            content,
+            "# comment\n[day01]\npart2 = 11\npart1 = 1\n\n[day02]\npart1 = 2\n"
+        );
+    }
+
+    #[test]
+    fn new_table() {
+        let content = record("table", Some("[day01]\npart1 = 1\n"), &[(3, "part1", "-7")]);
+        assert_eq!(content, "[day01]\npart1 = 1\n\n[day03]\npart1 = -7\n");
+    }
+
+    #[test]
+    fn without_trailing_newline() {
+        let content = record("newline", Some("[day01]\npart1 = 1"), &[(2, "part1", "2")]);
+        assert_eq!(content, "[day01]\npart1 = 1\n\n[day02]\npart1 = 2\n");
+        let content = record("header", Some("[day01]"), &[(1, "part1", "1")]);
+        assert_eq!(content, "[day01]\npart1 = 1\n");
+    }
+}
@@ -0,0 +1,99 @@
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use md5::Digest;
+
+use crate::{Answer, Task};
+
+/// Where answers are cached and the source fingerprint of each year and day,
+/// an answer is only reused for the same input, parameters and fingerprint
+pub struct Cache {
+    pub dir: PathBuf,
+    pub fingerprints: &'static [(u16, u8, &'static str)],
+}
+
+static CACHE: RwLock<Option<Cache>> = RwLock::new(None);
+
+/// Enable or disable the answer cache for the tasks run after this
+pub fn set_cache(cache: Option<Cache>) {
+    *CACHE.write().unwrap_or_else(|err| err.into_inner()) = cache;
+}
+
+pub fn input_hash(input: &str) -> String {
+    md5::Md5::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// The cache file of the task and input, and the fingerprint its answer has to match,
+/// `None` when caching is disabled or the task has no fingerprint
+fn entry(task: &Task, input: &str) -> Option<(PathBuf, String)> {
+    let cache = CACHE.read().unwrap_or_else(|err| err.into_inner());
+    let cache = cache.as_ref()?;
+    let (year, day) = task.year_day()?;
+    let (_, _, fingerprint) = cache
+        .fingerprints
+        .iter()
+        .find(|(fp_year, fp_day, _)| (*fp_year, *fp_day) == (year, day))?;
+
+    let params = crate::params::values(task)
+        .into_iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect::<Vec<_>>()
+        .join(",");
+    let file = format!(
+        "{}-{}.json",
+        task.path.join("-"),
+        input_hash(&format!("{}\n{params}", input_hash(input)))
+    );
+    Some((cache.dir.join(file), (*fingerprint).to_owned()))
+}
+
+fn to_json(answer: &Answer, fingerprint: &str) -> Option<String> {
+    let (kind, value) = match answer {
+        Answer::Unsigned(value) => ("unsigned", value.to_string()),
+        Answer::Signed(value) => ("signed", value.to_string()),
+        Answer::Text(value) => ("text", value.clone()),
+        Answer::Art(value) => ("art", value.clone()),
+        Answer::Unit => return None,
+    };
+    Some(
+        serde_json::json!({ "fingerprint": fingerprint, "kind": kind, "value": value }).to_string(),
+    )
+}
+
+fn from_json(content: &str, fingerprint: &str) -> Option<Answer> {
+    let json = serde_json::from_str::<serde_json::Value>(content).ok()?;
+    if json["fingerprint"].as_str()? != fingerprint {
+        return None;
+    }
+    let value = json["value"].as_str()?;
+    match json["kind"].as_str()? {
+        "unsigned" => value.parse().ok().map(Answer::Unsigned),
+        "signed" => value.parse().ok().map(Answer::Signed),
+        "text" => Some(Answer::Text(value.to_owned())),
+        "art" => Some(Answer::Art(value.to_owned())),
+        _ => None,
+    }
+}
+
+/// The cached answer of the task for this input, if it was computed by the same sources
+pub(crate) fn lookup(task: &Task, input: &str) -> Option<Answer> {
+    let (path, fingerprint) = entry(task, input)?;
+    from_json(&std::fs::read_to_string(path).ok()?, &fingerprint)
+}
+
+/// Cache the answer of the task for this input, tasks only run for their side effects are not cached
+pub(crate) fn store(task: &Task, input: &str, answer: &Answer) -> std::io::Result<()> {
+    let Some((path, fingerprint)) = entry(task, input) else {
+        return Ok(());
+    };
+    let Some(json) = to_json(answer, &fingerprint) else {
+        return Ok(());
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, json)
+}
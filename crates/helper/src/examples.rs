@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use libtest_mimic::{Arguments, Trial};
+
+use crate::answers::{AnswersError, Verdict};
+
+/// An example input together with the answers expected for it,
+/// stored next to `dayDD.NAME.txt` as `dayDD.NAME.answers`
+///
+/// ```toml
+/// part1 = 7
+/// part2 = "text"
+///
+/// # optional, the parameters the example uses instead of the defaults
+/// [params]
+/// steps = 10
+/// ```
+#[derive(Debug)]
+pub struct Example {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub input: PathBuf,
+    pub answers: Vec<(String, String)>,
+    /// the parameter overrides the answers were computed with
+    pub params: BTreeMap<String, String>,
+}
+
+pub fn examples_dir(inputs_root: &Path, year: u16) -> PathBuf {
+    inputs_root.join(format!("example/year{year}"))
+}
+
+/// All examples of a year that have an answers file, ordered by day and name
+pub fn load_examples(inputs_root: &Path, year: u16) -> Result<Vec<Example>, AnswersError> {
+    let dir = examples_dir(inputs_root, year);
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(AnswersError::Io(dir, err)),
+    };
+
+    let mut examples = vec![];
+    for entry in entries {
+        let path = entry
+            .map_err(|err| AnswersError::Io(dir.clone(), err))?
+            .path();
+        let Some(stem) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(".answers"))
+        else {
+            continue;
+        };
+        let Some((day, name)) = stem
+            .split_once('.')
+            .and_then(|(day, name)| Some((day.strip_prefix("day")?.parse().ok()?, name)))
+        else {
+            return Err(AnswersError::Parse(
+                path.clone(),
+                "expected a file name like day01.example1.answers".to_owned(),
+            ));
+        };
+
+        let content =
+            std::fs::read_to_string(&path).map_err(|err| AnswersError::Io(path.clone(), err))?;
+        let mut table = content
+            .parse::<toml::Table>()
+            .map_err(|err| AnswersError::Parse(path.clone(), err.to_string()))?;
+        let value = |key: &str, value| {
+            crate::answers::answer_value(value).ok_or_else(|| {
+                AnswersError::Parse(
+                    path.clone(),
+                    format!("{key} is neither an integer nor a string"),
+                )
+            })
+        };
+        let params = match table.remove("params") {
+            None => BTreeMap::new(),
+            Some(toml::Value::Table(params)) => params
+                .into_iter()
+                .map(|(name, param)| Ok((name.clone(), value(&format!("params.{name}"), param)?)))
+                .collect::<Result<_, _>>()?,
+            Some(_) => {
+                return Err(AnswersError::Parse(
+                    path.clone(),
+                    "params is not a table".to_owned(),
+                ));
+            }
+        };
+        let answers = table
+            .into_iter()
+            .map(|(part, answer)| Ok((part.clone(), value(&part, answer)?)))
+            .collect::<Result<_, _>>()?;
+
+        examples.push(Example {
+            year,
+            day,
+            name: name.to_owned(),
+            input: dir.join(format!("{stem}.txt")),
+            answers,
+            params,
+        });
+    }
+
+    examples.sort_by(|a, b| (a.day, &a.name).cmp(&(b.day, &b.name)));
+    Ok(examples)
+}
+
+/// Run the task for a part of the example with the example's parameters
+/// and compare its answer with the expected one
+pub fn check_example(example: &Example, part: &str, expected: &str) -> Result<(), String> {
+    let part_number = part
+        .strip_prefix("part")
+        .and_then(|part| part.parse().ok())
+        .ok_or_else(|| format!("{part} is not a part, expected e.g. part1"))?;
+    let input = crate::input::read_input(&example.input).map_err(|err| err.to_string())?;
+    let answer = crate::params::with_overrides(&example.params, || {
+        crate::solve(example.year, example.day, part_number, &input)
+    })
+    .map_err(|err| err.to_string())?;
+
+    match crate::answers::check(Some(expected), &answer) {
+        Verdict::Pass | Verdict::Unknown => Ok(()),
+        Verdict::Fail { expected, actual } => Err(format!("expected {expected}, got {actual}")),
+    }
+}
+
+/// Run every example answer of a year as its own test case,
+/// meant as the `main` of a test target with `harness = false`
+pub fn test_examples(year: u16) -> ! {
+    let inputs_root = crate::input::inputs_root();
+    let examples = load_examples(&inputs_root, year).unwrap_or_else(|err| panic!("{err}"));
+
+    let trials = examples
+        .into_iter()
+        .flat_map(|example| {
+            let example = std::sync::Arc::new(example);
+            example
+                .answers
+                .clone()
+                .into_iter()
+                .map(move |(part, expected)| {
+                    let example = example.clone();
+                    Trial::test(
+                        format!("day{:02}::{}::{part}", example.day, example.name),
+                        move || Ok(check_example(&example, &part, &expected)?),
+                    )
+                })
+        })
+        .collect();
+
+    libtest_mimic::run(&Arguments::from_args(), trials).exit()
+}
@@ -0,0 +1,313 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::Answer;
+use crate::answers::AnswersError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuessVerdict {
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl GuessVerdict {
+    fn as_str(&self) -> &'static str {
+        match self {
+            GuessVerdict::TooHigh => "too high",
+            GuessVerdict::TooLow => "too low",
+            GuessVerdict::Wrong => "wrong",
+        }
+    }
+}
+
+impl Display for GuessVerdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for GuessVerdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "too high" | "too-high" | "high" => Ok(GuessVerdict::TooHigh),
+            "too low" | "too-low" | "low" => Ok(GuessVerdict::TooLow),
+            "wrong" => Ok(GuessVerdict::Wrong),
+            _ => Err(format!(
+                "{s} is not a valid verdict, expected one of too-high, too-low or wrong"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub answer: String,
+    pub verdict: Option<GuessVerdict>,
+}
+
+/// The answers already submitted for one year, stored as
+///
+/// ```toml
+/// [[day10.part2]]
+/// answer = 8788
+/// verdict = "too low"
+/// ```
+#[derive(Debug, Default)]
+pub struct Guesses {
+    guesses: BTreeMap<(u8, String), Vec<Guess>>,
+}
+
+pub fn guesses_path(inputs_root: &Path, year: u16) -> PathBuf {
+    crate::input::answers_dir(inputs_root).join(format!("year{year}.guesses.toml"))
+}
+
+impl Guesses {
+    /// Load the guesses for a year, a missing guesses file is treated as having no guesses
+    pub fn load(inputs_root: &Path, year: u16) -> Result<Self, AnswersError> {
+        let path = guesses_path(inputs_root, year);
+        match std::fs::read_to_string(&path) {
+            Ok(content) => Self::parse(&content).map_err(|err| AnswersError::Parse(path, err)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(AnswersError::Io(path, err)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let table = content
+            .parse::<toml::Table>()
+            .map_err(|err| err.to_string())?;
+
+        let mut guesses = BTreeMap::new();
+        for (day_key, parts) in table {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("{day_key} is not a valid day key, expected e.g. day01"))?;
+            let toml::Value::Table(parts) = parts else {
+                return Err(format!("{day_key} is not a table"));
+            };
+            for (part, entries) in parts {
+                let toml::Value::Array(entries) = entries else {
+                    return Err(format!("{day_key}.{part} is not an array of tables"));
+                };
+                let entries = entries
+                    .into_iter()
+                    .map(|entry| {
+                        parse_guess(entry).map_err(|err| format!("{day_key}.{part}: {err}"))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                guesses.insert((day, part), entries);
+            }
+        }
+
+        Ok(Self { guesses })
+    }
+
+    pub fn for_part(&self, day: u8, part: &str) -> &[Guess] {
+        self.guesses
+            .get(&(day, part.to_owned()))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Warnings for an answer that was already guessed or lies outside the known bounds
+    pub fn check(&self, day: u8, part: &str, answer: &Answer) -> Vec<String> {
+        let guesses = self.for_part(day, part);
+        let actual = answer.to_string();
+        let mut warnings = vec![];
+
+        for guess in guesses.iter().filter(|guess| guess.answer == actual) {
+            match guess.verdict {
+                Some(verdict) => warnings.push(format!("{actual} is known to be {verdict}")),
+                None => warnings.push(format!("{actual} was already guessed")),
+            }
+        }
+
+        let Ok(actual) = actual.parse::<i128>() else {
+            return warnings;
+        };
+        let numeric = |verdict| {
+            guesses
+                .iter()
+                .filter(move |guess| guess.verdict == Some(verdict))
+                .flat_map(|guess| guess.answer.parse::<i128>().ok())
+        };
+        if let Some(upper) = numeric(GuessVerdict::TooHigh).min()
+            && actual > upper
+        {
+            warnings.push(format!(
+                "{actual} is larger than {upper}, which is too high"
+            ));
+        }
+        if let Some(lower) = numeric(GuessVerdict::TooLow).max()
+            && actual < lower
+        {
+            warnings.push(format!(
+                "{actual} is smaller than {lower}, which is too low"
+            ));
+        }
+
+        warnings
+    }
+}
+
+fn parse_guess(entry: toml::Value) -> Result<Guess, String> {
+    let toml::Value::Table(mut entry) = entry else {
+        return Err("guess is not a table".to_owned());
+    };
+    let answer = match entry.remove("answer") {
+        Some(toml::Value::Integer(answer)) => answer.to_string(),
+        Some(toml::Value::String(answer)) => answer,
+        _ => return Err("guess has no integer or string answer".to_owned()),
+    };
+    let verdict = match entry.remove("verdict") {
+        None => None,
+        Some(toml::Value::String(verdict)) => Some(verdict.parse()?),
+        Some(_) => return Err("verdict is not a string".to_owned()),
+    };
+    Ok(Guess { answer, verdict })
+}
+
+/// Append a guess to the guesses file of a year, creating it if necessary
+pub fn record_guess(
+    inputs_root: &Path,
+    year: u16,
+    day: u8,
+    part: &str,
+    guess: &Guess,
+) -> std::io::Result<()> {
+    let path = guesses_path(inputs_root, year);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(path)?;
+
+    let answer = match guess.answer.parse::<i64>() {
+        Ok(answer) => answer.to_string(),
+        Err(_) => toml::Value::String(guess.answer.clone()).to_string(),
+    };
+    writeln!(file, "\n[[day{day:02}.{part}]]")?;
+    writeln!(file, "answer = {answer}")?;
+    if let Some(verdict) = guess.verdict {
+        writeln!(file, "verdict = \"{verdict}\"")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GUESSES: &str = r#"
+[[day10.part2]]
+answer = 100
+verdict = "too high"
+
+[[day10.part2]]
+answer = 80
+verdict = "too high"
+
+[[day10.part2]]
+answer = 20
+verdict = "too low"
+
+[[day10.part2]]
+answer = 40
+verdict = "too low"
+
+[[day10.part2]]
+answer = 50
+verdict = "wrong"
+
+[[day10.part2]]
+answer = "abc"
+"#;
+
+    fn check(answer: impl Into<Answer>) -> Vec<String> {
+        Guesses::parse(GUESSES)
+            .unwrap()
+            .check(10, "part2", &answer.into())
+    }
+
+    #[test]
+    fn within_bounds() {
+        assert!(check(60).is_empty());
+        assert!(Guesses::default().check(10, "part2", &60.into()).is_empty());
+    }
+
+    #[test]
+    fn lowest_too_high_bound() {
+        assert_eq!(check(90), ["90 is larger than 80, which is too high"]);
+        assert!(check(79).is_empty());
+    }
+
+    #[test]
+    fn highest_too_low_bound() {
+        assert_eq!(check(30), ["30 is smaller than 40, which is too low"]);
+        assert!(check(41).is_empty());
+    }
+
+    #[test]
+    fn repeated_guess() {
+        assert_eq!(check(50), ["50 is known to be wrong"]);
+        assert_eq!(
+            check(100),
+            [
+                "100 is known to be too high",
+                "100 is larger than 80, which is too high"
+            ]
+        );
+        assert_eq!(check("abc"), ["abc was already guessed"]);
+    }
+
+    #[test]
+    fn non_numeric_answer() {
+        assert!(check("xyz").is_empty());
+        assert!(check("1,2").is_empty());
+    }
+
+    #[test]
+    fn other_parts() {
+        let guesses = Guesses::parse(GUESSES).unwrap();
+        assert!(guesses.check(10, "part1", &100.into()).is_empty());
+        assert!(guesses.check(11, "part2", &100.into()).is_empty());
+    }
+
+    #[test]
+    fn record_and_load() {
+        let root = std::env::temp_dir().join(format!("helper-guesses-{}", std::process::id()));
+        let path = guesses_path(&root, 2023);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        // a hand-edited file without a trailing newline
+        std::fs::write(&path, "[[day01.part1]]\nanswer = 5").unwrap();
+
+        let high = Guess {
+            answer: "12".to_owned(),
+            verdict: Some(GuessVerdict::TooHigh),
+        };
+        let text = Guess {
+            answer: "a \"b\"".to_owned(),
+            verdict: None,
+        };
+        record_guess(&root, 2023, 1, "part1", &high).unwrap();
+        record_guess(&root, 2023, 8, "part2", &text).unwrap();
+        let guesses = Guesses::load(&root, 2023);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        let guesses = guesses.unwrap();
+        let five = Guess {
+            answer: "5".to_owned(),
+            verdict: None,
+        };
+        assert_eq!(guesses.for_part(1, "part1"), [five, high]);
+        assert_eq!(guesses.for_part(8, "part2"), [text]);
+    }
+}
@@ -0,0 +1,205 @@
+use std::fmt::Display;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+
+pub const INPUTS_ROOT_ENV: &str = "AOC_INPUTS";
+pub const PROFILE_ENV: &str = "AOC_PROFILE";
+/// the profile using `personal/yearYYYY` and `answers/yearYYYY.toml` directly below the inputs root
+pub const DEFAULT_PROFILE: &str = "default";
+pub const DEFAULT_INPUTS_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../inputs");
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io(PathBuf, std::io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "input not found: {}", path.display()),
+            InputError::Io(path, err) => write!(f, "failed to read {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The inputs root from the `AOC_INPUTS` environment variable,
+/// falling back to the `inputs` directory of this workspace
+pub fn inputs_root() -> PathBuf {
+    std::env::var_os(INPUTS_ROOT_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUTS_ROOT))
+}
+
+static PROFILE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Select the profile whose personal inputs and answers are used
+pub fn set_profile(profile: &str) {
+    *PROFILE.write().unwrap_or_else(|err| err.into_inner()) = Some(profile.to_owned());
+}
+
+/// The selected profile, falling back to the `AOC_PROFILE` environment variable
+/// and then the default profile
+pub fn profile() -> String {
+    PROFILE
+        .read()
+        .unwrap_or_else(|err| err.into_inner())
+        .clone()
+        .or_else(|| {
+            std::env::var(PROFILE_ENV)
+                .ok()
+                .filter(|profile| !profile.is_empty())
+        })
+        .unwrap_or_else(|| DEFAULT_PROFILE.to_owned())
+}
+
+fn profile_dir(dir: PathBuf) -> PathBuf {
+    match profile().as_str() {
+        DEFAULT_PROFILE => dir,
+        profile => dir.join(profile),
+    }
+}
+
+/// The personal inputs of the selected profile, `personal/<profile>` below the inputs root
+pub fn personal_dir(root: &Path) -> PathBuf {
+    profile_dir(root.join("personal"))
+}
+
+/// The known answers and guesses of the selected profile, `answers/<profile>` below the inputs root
+pub fn answers_dir(root: &Path) -> PathBuf {
+    profile_dir(root.join("answers"))
+}
+
+/// All profiles with personal inputs, the default profile first if it has any inputs
+pub fn profiles(root: &Path) -> Vec<String> {
+    let mut default = false;
+    let mut named = vec![];
+    for entry in std::fs::read_dir(root.join("personal"))
+        .into_iter()
+        .flatten()
+    {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if !entry.path().is_dir() {
+            continue;
+        }
+        let Some(name) = entry.file_name().to_str().map(str::to_owned) else {
+            continue;
+        };
+        if name
+            .strip_prefix("year")
+            .is_some_and(|year| year.parse::<u16>().is_ok())
+        {
+            default = true;
+        } else if !name.starts_with('.') {
+            named.push(name);
+        }
+    }
+    named.sort();
+    if default || named.is_empty() {
+        named.insert(0, DEFAULT_PROFILE.to_owned());
+    }
+    named
+}
+
+pub fn personal_input_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    personal_dir(root).join(format!("year{year}/day{day:02}.txt"))
+}
+
+pub fn example_input_path(root: &Path, year: u16, day: u8, example: &str) -> PathBuf {
+    root.join(format!(
+        "example/year{year}/day{day:02}.example{example}.txt"
+    ))
+}
+
+/// Where tasks load their input from
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// the personal input of the task's year and day below the inputs root
+    #[default]
+    Personal,
+    /// the same file for every task
+    File(PathBuf),
+    /// standard input, read once and shared by every task
+    Stdin,
+    /// the example with that name of the task's year and day, e.g. `1` for `dayDD.example1.txt`
+    Example(String),
+}
+
+impl InputSource {
+    /// Parse the value of `--input`, where `-` stands for standard input
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+}
+
+static SOURCE: RwLock<InputSource> = RwLock::new(InputSource::Personal);
+static STDIN: OnceLock<Result<String, (std::io::ErrorKind, String)>> = OnceLock::new();
+
+/// Replace where tasks load their input from, the default is their personal input
+pub fn set_input_source(source: InputSource) {
+    *SOURCE.write().unwrap_or_else(|err| err.into_inner()) = source;
+}
+
+pub fn input_source() -> InputSource {
+    SOURCE.read().unwrap_or_else(|err| err.into_inner()).clone()
+}
+
+fn read_stdin() -> Result<String, InputError> {
+    STDIN
+        .get_or_init(|| {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .map(|_| input)
+                .map_err(|err| (err.kind(), err.to_string()))
+        })
+        .clone()
+        .map_err(|(kind, err)| InputError::Io(PathBuf::from("-"), std::io::Error::new(kind, err)))
+}
+
+/// Load the input for a year and day from the current [`InputSource`],
+/// tasks not belonging to a year and day get an empty input unless a file or stdin is used
+pub fn load_input(root: &Path, year_day: Option<(u16, u8)>) -> Result<String, InputError> {
+    match (input_source(), year_day) {
+        (InputSource::File(path), _) => read_input(&path),
+        (InputSource::Stdin, _) => read_stdin(),
+        (_, None) => Ok(String::new()),
+        (InputSource::Personal, Some((year, day))) => load_personal_input(root, year, day),
+        (InputSource::Example(example), Some((year, day))) => {
+            read_input(&example_input_path(root, year, day, &example))
+        }
+    }
+}
+
+pub fn read_input(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|err| match err.kind() {
+        std::io::ErrorKind::NotFound => InputError::NotFound(path.to_owned()),
+        _ => InputError::Io(path.to_owned(), err),
+    })
+}
+
+pub fn load_personal_input(root: &Path, year: u16, day: u8) -> Result<String, InputError> {
+    read_input(&personal_input_path(root, year, day))
+}
+
+/// Load the personal input for a test, returning early from the test when it is not available
+#[macro_export]
+macro_rules! personal_input_or_skip {
+    ($year:literal, $day:literal) => {
+        match $crate::input::load_personal_input(&$crate::input::inputs_root(), $year, $day) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("skipping: {err}");
+                return;
+            }
+        }
+    };
+}
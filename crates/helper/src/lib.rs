@@ -1,12 +1,166 @@
 use linkme::distributed_slice;
+use std::fmt::Write;
 use std::ops::{Div, Mul, Not, Rem, Sub};
-
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use input::InputError;
+
+pub use answer::Answer;
+pub use params::{Param, param};
+pub use solve::{SolveError, find_task, solve};
+
+pub mod alloc;
+pub mod answer;
+pub mod answers;
+pub mod cache;
+pub mod examples;
+pub mod guesses;
+pub mod input;
 pub mod iter;
+pub mod output;
+pub mod parallel;
+pub mod params;
+pub mod pattern;
+pub mod solve;
 
 pub struct Task {
     pub path: &'static [&'static str],
-    pub run: fn(),
-    pub include_in_all: bool,
+    pub run: fn(&str) -> Answer,
+    /// tagged tasks are skipped by `all` unless their tags are selected explicitly
+    pub tags: &'static [Tag],
+    /// the puzzle parameters read through [`param`], which can be overridden when running the task
+    pub params: &'static [Param],
+}
+
+/// An alternative implementation of the task with the same path,
+/// run next to the task by `crosscheck` to compare answers and timings
+pub struct Variant {
+    pub path: &'static [&'static str],
+    pub name: &'static str,
+    pub run: fn(&str) -> Answer,
+}
+
+/// The input parsing shared by the tasks starting with the path, e.g. all parts of a day,
+/// timed on its own by `bench` to tell parsing and solving apart
+pub struct Parser {
+    pub path: &'static [&'static str],
+    /// parses the whole input, handing the parsed values to [`consume`]
+    pub run: fn(&str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tag {
+    Slow,
+    Debug,
+    Visualization,
+    Unfinished,
+}
+
+impl Tag {
+    pub const ALL: [Tag; 4] = [Tag::Slow, Tag::Debug, Tag::Visualization, Tag::Unfinished];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tag::Slow => "slow",
+            Tag::Debug => "debug",
+            Tag::Visualization => "visualization",
+            Tag::Unfinished => "unfinished",
+        }
+    }
+}
+
+impl std::fmt::Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for Tag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Tag::ALL
+            .into_iter()
+            .find(|tag| tag.name() == s)
+            .ok_or_else(|| {
+                let names = Tag::ALL.map(|tag| tag.name()).join(", ");
+                format!("{s} is not a valid tag, expected one of {names}")
+            })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TagFilter {
+    pub tags: Vec<Tag>,
+    pub exclude_tags: Vec<Tag>,
+}
+
+impl TagFilter {
+    /// Whether the task matches the filter,
+    /// i.e. has none of the excluded tags and one of the selected tags if any are selected
+    pub fn selects(&self, task: &Task) -> bool {
+        !task.tags.iter().any(|tag| self.exclude_tags.contains(tag))
+            && (self.tags.is_empty() || task.tags.iter().any(|tag| self.tags.contains(tag)))
+    }
+
+    /// Whether the task should be run by default,
+    /// i.e. is selected and all of its tags have been selected explicitly
+    pub fn runs(&self, task: &Task) -> bool {
+        self.selects(task) && task.tags.iter().all(|tag| self.tags.contains(tag))
+    }
+}
+
+impl Task {
+    /// The year and day this task belongs to, if its path starts with them
+    pub fn year_day(&self) -> Option<(u16, u8)> {
+        match self.path {
+            [year, day, ..] => Some((year.parse().ok()?, day.parse().ok()?)),
+            _ => None,
+        }
+    }
+
+    /// Run the task on the input with its parameters in scope
+    pub fn solve(&self, input: &str) -> Answer {
+        params::with_params(self, || (self.run)(input))
+    }
+
+    /// Like [`Task::solve`], but a panic is caught and returned as its message,
+    /// the panic hook still reports the panic as usual
+    pub fn try_solve(&self, input: &str) -> Result<Answer, String> {
+        std::panic::catch_unwind(|| self.solve(input))
+            .map_err(|payload| panic_message(payload.as_ref()))
+    }
+
+    /// Run a variant of the task on the input with the parameters of the task in scope
+    pub fn solve_variant(&self, variant: &Variant, input: &str) -> Answer {
+        params::with_params(self, || (variant.run)(input))
+    }
+
+    /// The variants registered for this task
+    pub fn variants(&self) -> impl Iterator<Item = &'static Variant> + use<'_> {
+        VARIANTS
+            .iter()
+            .filter(move |variant| variant.path == self.path)
+    }
+
+    /// The parser registered for the input of this task, if any
+    pub fn parser(&self) -> Option<&'static Parser> {
+        PARSERS
+            .iter()
+            .find(|parser| self.path.starts_with(parser.path))
+    }
+
+    /// Only parse the input the way the task does, with the parameters of the task in scope
+    pub fn run_parser(&self, parser: &Parser, input: &str) {
+        params::with_params(self, || (parser.run)(input))
+    }
+
+    /// Load the input for this task from the current [`input::InputSource`],
+    /// by default the personal input below the inputs root
+    pub fn load_input(&self, inputs_root: &Path) -> Result<String, InputError> {
+        input::load_input(inputs_root, self.year_day())
+    }
 }
 
 pub struct Position<T, const DIM: usize> {
@@ -42,7 +196,18 @@ impl Direction {
 #[distributed_slice]
 pub static TASKS: [Task];
 
-pub fn list_with_prefix<S: AsRef<str>>(prefix: &[S]) {
+#[distributed_slice]
+pub static VARIANTS: [Variant];
+
+#[distributed_slice]
+pub static PARSERS: [Parser];
+
+/// Drop a parsed value without letting the optimizer skip computing it
+pub fn consume<T>(value: T) {
+    drop(std::hint::black_box(value));
+}
+
+pub fn list_with_prefix<S: AsRef<str>>(prefix: &[S], filter: &TagFilter) {
     if prefix.is_empty() {
         println!("Available tasks: ");
     } else {
@@ -55,32 +220,291 @@ pub fn list_with_prefix<S: AsRef<str>>(prefix: &[S]) {
                 .join(" ")
         )
     }
-    for task in tasks_filtered_by_prefix(prefix) {
-        println!("\t{}", task.path.join(" "));
+    let mut found = false;
+    for task in tasks_filtered_by_prefix(prefix).filter(|task| filter.selects(task)) {
+        found = true;
+        let mut details = String::new();
+        if !task.tags.is_empty() {
+            let tags = task.tags.iter().map(Tag::name).collect::<Vec<_>>();
+            _ = write!(details, " [{}]", tags.join(", "));
+        }
+        for param in task.params {
+            _ = write!(details, " {param}");
+        }
+        let variants = task
+            .variants()
+            .map(|variant| variant.name)
+            .collect::<Vec<_>>();
+        if !variants.is_empty() {
+            _ = write!(details, " (variants: {})", variants.join(", "));
+        }
+        if details.is_empty() {
+            println!("\t{}", task.path.join(" "));
+        } else {
+            println!("\t{:<24}{details}", task.path.join(" "));
+        }
+    }
+    if !found {
+        suggest_closest(prefix, true);
     }
 }
 
+/// All tasks whose path starts with the prefix, in numeric path order,
+/// the prefix may contain numbers, ranges and lists as understood by [`pattern::PathPattern`]
 pub fn tasks_filtered_by_prefix<S: AsRef<str>>(
     prefix: &[S],
-) -> impl Iterator<Item = &'static Task> + '_ {
-    TASKS.iter().filter(move |task| {
-        prefix.len() <= task.path.len()
-            && task
-                .path
-                .iter()
-                .zip(prefix)
-                .all(|(task, prefix)| *task == prefix.as_ref())
-    })
+) -> impl Iterator<Item = &'static Task> + use<S> {
+    let pattern = pattern::PathPattern::parse(prefix);
+    sorted_tasks().filter(move |task| pattern.matches_prefix(task.path))
 }
 
-pub fn run_all_prefix<S: AsRef<str>>(prefix: &[S]) {
-    for task in tasks_filtered_by_prefix(prefix) {
-        if task.include_in_all {
-            println!("Running {}", task.path.join(" "));
-            (task.run)()
-        } else {
-            println!("Skipping {}", task.path.join(" "))
+/// All tasks whose whole path matches the arguments, in numeric path order
+pub fn tasks_matching<S: AsRef<str>>(args: &[S]) -> impl Iterator<Item = &'static Task> + use<S> {
+    let pattern = pattern::PathPattern::parse(args);
+    sorted_tasks().filter(move |task| pattern.matches(task.path))
+}
+
+fn sorted_tasks() -> impl Iterator<Item = &'static Task> {
+    let mut tasks = TASKS.iter().collect::<Vec<_>>();
+    tasks.sort_by_cached_key(|task| {
+        task.path
+            .iter()
+            .map(|segment| (pattern::segment_number(segment), *segment))
+            .collect::<Vec<_>>()
+    });
+    tasks.into_iter()
+}
+
+/// Print a suggestion for arguments that didn't match any task
+pub fn suggest_closest<S: AsRef<str>>(args: &[S], prefix: bool) {
+    let args_str = args
+        .iter()
+        .map(|s| s.as_ref())
+        .collect::<Vec<_>>()
+        .join(" ");
+    match pattern::closest_path(args, TASKS.iter().map(|task| task.path), prefix) {
+        Some(path) => {
+            let len = if prefix {
+                args.len().min(path.len())
+            } else {
+                path.len()
+            };
+            println!(
+                "No task matches `{args_str}`, did you mean `{}`?",
+                path[..len].join(" ")
+            );
+        }
+        None => println!("No task matches `{args_str}`"),
+    }
+}
+
+pub enum Outcome {
+    Succeeded(Answer),
+    Failed(String),
+    Panicked(String),
+    TimedOut(Duration),
+    Skipped,
+}
+
+pub struct RunOptions {
+    pub jobs: usize,
+    pub timeout: Option<Duration>,
+    pub filter: TagFilter,
+    pub format: output::OutputFormat,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            jobs: 1,
+            timeout: None,
+            filter: TagFilter::default(),
+            format: output::OutputFormat::default(),
+        }
+    }
+}
+
+/// Run all tasks with the prefix that are selected by the tag filter and print a summary,
+/// returns whether all of them succeeded
+///
+/// The output of each task is printed in path order, even when running on multiple threads,
+/// with a structured [`output::OutputFormat`] one record per task is printed instead of the output and summary,
+/// printing its header is left to the caller so runs for several profiles share one
+pub fn run_all_prefix<S: AsRef<str>>(
+    prefix: &[S],
+    inputs_root: &Path,
+    options: &RunOptions,
+) -> bool {
+    let tasks = tasks_filtered_by_prefix(prefix)
+        .filter(|task| options.filter.selects(task))
+        .collect::<Vec<_>>();
+    let jobs = tasks
+        .iter()
+        .map(|&task| -> parallel::Job<(String, Outcome, Duration)> {
+            let inputs_root = inputs_root.to_owned();
+            let runs = options.filter.runs(task);
+            Box::new(move || {
+                let mut output = String::new();
+                let start = Instant::now();
+                let outcome = if runs {
+                    _ = writeln!(output, "Running {}", task.path.join(" "));
+                    run_task_captured(task, &inputs_root, &mut output)
+                } else {
+                    _ = writeln!(output, "Skipping {}", task.path.join(" "));
+                    Outcome::Skipped
+                };
+                (output, outcome, start.elapsed())
+            })
+        })
+        .collect();
+
+    if tasks.is_empty() {
+        suggest_closest(prefix, true);
+        return false;
+    }
+
+    let format = options.format;
+    let mut outcomes = vec![];
+    parallel::run_ordered(jobs, options.jobs, options.timeout, |idx, result| {
+        let task = tasks[idx];
+        let (output, outcome, duration) = result.unwrap_or_else(|| {
+            let timeout = options.timeout.unwrap_or_default();
+            let output = format!(
+                "Running {0}\nTimed out {0} after {timeout:?}\n",
+                task.path.join(" ")
+            );
+            (output, Outcome::TimedOut(timeout), timeout)
+        });
+        match format.record(task, &outcome, duration) {
+            Some(record) => println!("{record}"),
+            None => print!("{output}"),
+        }
+        outcomes.push((task, outcome));
+    });
+
+    if format == output::OutputFormat::Text {
+        print_summary(&outcomes)
+    } else {
+        outcomes
+            .iter()
+            .all(|(_, outcome)| matches!(outcome, Outcome::Succeeded(_) | Outcome::Skipped))
+    }
+}
+
+pub fn print_summary(outcomes: &[(&Task, Outcome)]) -> bool {
+    let count = |filter: fn(&Outcome) -> bool| {
+        outcomes
+            .iter()
+            .filter(|(_, outcome)| filter(outcome))
+            .count()
+    };
+    let failed = count(|outcome| matches!(outcome, Outcome::Failed(_)));
+    let panicked = count(|outcome| matches!(outcome, Outcome::Panicked(_)));
+    let timed_out = count(|outcome| matches!(outcome, Outcome::TimedOut(_)));
+
+    println!();
+    println!(
+        "Summary: {} succeeded, {failed} failed, {panicked} panicked, {timed_out} timed out, {} skipped",
+        count(|outcome| matches!(outcome, Outcome::Succeeded(_))),
+        count(|outcome| matches!(outcome, Outcome::Skipped)),
+    );
+    for (task, outcome) in outcomes {
+        match outcome {
+            Outcome::Succeeded(_) => {}
+            Outcome::Failed(err) => println!("\tfailed    {:<16} {err}", task.path.join(" ")),
+            Outcome::Panicked(msg) => println!("\tpanicked  {:<16} {msg}", task.path.join(" ")),
+            Outcome::TimedOut(timeout) => {
+                println!("\ttimed out {:<16} after {timeout:?}", task.path.join(" "))
+            }
+            Outcome::Skipped => println!("\tskipped   {}", task.path.join(" ")),
+        }
+    }
+
+    failed == 0 && panicked == 0 && timed_out == 0
+}
+
+/// Load the input of a task, run it and print the answer,
+/// warning when the answer is already known to be wrong,
+/// or print its record when using a structured format
+///
+/// Panics of the task are caught and reported as [`Outcome::Panicked`]
+pub fn run_task(task: &Task, inputs_root: &Path, format: output::OutputFormat) -> Outcome {
+    let mut output = String::new();
+    let start = Instant::now();
+    let outcome = run_task_captured(task, inputs_root, &mut output);
+    match format.record(task, &outcome, start.elapsed()) {
+        Some(record) => println!("{record}"),
+        None => print!("{output}"),
+    }
+    outcome
+}
+
+/// Like [`run_task`], but writes the answer and messages to `output` instead of stdout
+pub fn run_task_captured(task: &Task, inputs_root: &Path, output: &mut String) -> Outcome {
+    let input = match task.load_input(inputs_root) {
+        Ok(input) => input,
+        Err(err) => {
+            _ = writeln!(output, "Failed {}: {err}", task.path.join(" "));
+            return Outcome::Failed(err.to_string());
+        }
+    };
+    if let Some(answer) = cache::lookup(task, &input) {
+        _ = writeln!(output, "Cached {}", task.path.join(" "));
+        _ = writeln!(output, "{answer}");
+        for warning in known_wrong_warnings(task, inputs_root, &answer) {
+            _ = writeln!(output, "Warning {}: {warning}", task.path.join(" "));
+        }
+        return Outcome::Succeeded(answer);
+    }
+    let (result, stats) = alloc::measure(|| task.try_solve(&input));
+    match result {
+        Ok(answer) => {
+            if let Err(err) = cache::store(task, &input, &answer) {
+                _ = writeln!(
+                    output,
+                    "Warning {}: failed to cache the answer: {err}",
+                    task.path.join(" ")
+                );
+            }
+            if answer != Answer::Unit {
+                _ = writeln!(output, "{answer}");
+            }
+            if let Some(stats) = stats {
+                _ = writeln!(output, "Memory {}: {stats}", task.path.join(" "));
+            }
+            for warning in known_wrong_warnings(task, inputs_root, &answer) {
+                _ = writeln!(output, "Warning {}: {warning}", task.path.join(" "));
+            }
+            Outcome::Succeeded(answer)
         }
+        Err(msg) => {
+            _ = writeln!(output, "Panicked {}: {msg}", task.path.join(" "));
+            Outcome::Panicked(msg)
+        }
+    }
+}
+
+pub fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic payload".to_owned()
+    }
+}
+
+pub fn known_wrong_warnings(task: &Task, inputs_root: &Path, answer: &Answer) -> Vec<String> {
+    let (Some((year, day)), Some(part)) = (task.year_day(), task.path.last()) else {
+        return vec![];
+    };
+    // the guesses only apply to the personal input
+    if input::input_source() != input::InputSource::Personal {
+        return vec![];
+    }
+    match guesses::Guesses::load(inputs_root, year) {
+        Ok(guesses) => guesses.check(day, part, answer),
+        Err(err) => vec![err.to_string()],
     }
 }
 
@@ -0,0 +1,118 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::{Answer, Outcome, Task};
+
+/// How the runner reports task results, free-form text or one record per task
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    /// one JSON object per line
+    Json,
+    /// comma separated values with a header line
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!(
+                "{s} is not a valid format, expected one of text, json or csv"
+            )),
+        }
+    }
+}
+
+const CSV_COLUMNS: [&str; 6] = [
+    "path",
+    "profile",
+    "status",
+    "answer",
+    "duration_secs",
+    "error",
+];
+
+impl Outcome {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Succeeded(_) => "succeeded",
+            Outcome::Failed(_) => "failed",
+            Outcome::Panicked(_) => "panicked",
+            Outcome::TimedOut(_) => "timed_out",
+            Outcome::Skipped => "skipped",
+        }
+    }
+
+    pub fn answer(&self) -> Option<&Answer> {
+        match self {
+            Outcome::Succeeded(answer) if *answer != Answer::Unit => Some(answer),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<String> {
+        match self {
+            Outcome::Succeeded(_) | Outcome::Skipped => None,
+            Outcome::Failed(err) | Outcome::Panicked(err) => Some(err.clone()),
+            Outcome::TimedOut(timeout) => Some(format!("timed out after {timeout:?}")),
+        }
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+impl OutputFormat {
+    /// The line to print before the first record, if any
+    pub fn header(&self) -> Option<String> {
+        match self {
+            OutputFormat::Csv => Some(CSV_COLUMNS.join(",")),
+            OutputFormat::Text | OutputFormat::Json => None,
+        }
+    }
+
+    /// The record of one task run on the inputs of the selected profile,
+    /// `None` for the text format which prints the task output instead
+    pub fn record(&self, task: &Task, outcome: &Outcome, duration: Duration) -> Option<String> {
+        let path = task.path.join(" ");
+        let profile = crate::input::profile();
+        let answer = outcome.answer().map(Answer::to_string);
+        let error = outcome.error();
+        match self {
+            OutputFormat::Text => None,
+            OutputFormat::Json => Some(
+                serde_json::json!({
+                    "path": path,
+                    "profile": profile,
+                    "status": outcome.status(),
+                    "answer": answer,
+                    "duration_secs": duration.as_secs_f64(),
+                    "error": error,
+                })
+                .to_string(),
+            ),
+            OutputFormat::Csv => Some(
+                [
+                    csv_field(&path),
+                    csv_field(&profile),
+                    outcome.status().to_owned(),
+                    csv_field(answer.as_deref().unwrap_or_default()),
+                    duration.as_secs_f64().to_string(),
+                    csv_field(error.as_deref().unwrap_or_default()),
+                ]
+                .join(","),
+            ),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+/// the default stack size of the main thread on most platforms,
+/// some solutions recurse deep enough to overflow the smaller default for spawned threads
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+pub type Job<T> = Box<dyn FnOnce() -> T + Send>;
+
+/// Run the jobs on up to `threads` threads, calling `on_done` with the results in job order
+///
+/// Jobs running longer than `timeout` are reported as `None` and left running detached,
+/// as threads can't be cancelled. Without a timeout and with a single thread all jobs run
+/// on the current thread.
+pub fn run_ordered<T: Send + 'static>(
+    jobs: Vec<Job<T>>,
+    threads: usize,
+    timeout: Option<Duration>,
+    mut on_done: impl FnMut(usize, Option<T>),
+) {
+    if threads <= 1 && timeout.is_none() {
+        for (idx, job) in jobs.into_iter().enumerate() {
+            on_done(idx, Some(job()));
+        }
+        return;
+    }
+
+    let threads = threads.max(1);
+    let (sender, receiver) = mpsc::channel();
+    let mut pending = jobs.into_iter().enumerate();
+    let mut running = BTreeMap::<usize, Instant>::new();
+    let mut finished = BTreeMap::<usize, Option<T>>::new();
+    let mut next_to_report = 0;
+
+    loop {
+        while running.len() < threads {
+            let Some((idx, job)) = pending.next() else {
+                break;
+            };
+            let sender = sender.clone();
+            std::thread::Builder::new()
+                .name(format!("job-{idx}"))
+                .stack_size(STACK_SIZE)
+                .spawn(move || {
+                    // the receiver is gone when the job timed out, so the result can be dropped
+                    _ = sender.send((idx, job()));
+                })
+                .expect("failed to spawn job thread");
+            running.insert(idx, Instant::now());
+        }
+
+        if running.is_empty() {
+            break;
+        }
+
+        let wait = timeout.map(|timeout| {
+            running
+                .values()
+                .map(|start| timeout.saturating_sub(start.elapsed()))
+                .min()
+                .unwrap_or_default()
+        });
+        let received = match wait {
+            Some(wait) => receiver.recv_timeout(wait).ok(),
+            None => receiver.recv().ok(),
+        };
+
+        if let Some((idx, result)) = received
+            && running.remove(&idx).is_some()
+        {
+            finished.insert(idx, Some(result));
+        }
+        if let Some(timeout) = timeout {
+            running.retain(|&idx, start| {
+                let timed_out = start.elapsed() >= timeout;
+                if timed_out {
+                    finished.insert(idx, None);
+                }
+                !timed_out
+            });
+        }
+
+        while let Some(result) = finished.remove(&next_to_report) {
+            on_done(next_to_report, result);
+            next_to_report += 1;
+        }
+    }
+}
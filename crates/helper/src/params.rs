@@ -0,0 +1,108 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use crate::Task;
+
+/// A named puzzle parameter of a task, e.g. the number of days to simulate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+}
+
+impl Display for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.name, self.default)
+    }
+}
+
+static OVERRIDES: RwLock<BTreeMap<String, String>> = RwLock::new(BTreeMap::new());
+
+thread_local! {
+    static CURRENT: RefCell<Vec<(&'static str, String)>> = const { RefCell::new(vec![]) };
+    /// the overrides of [`with_overrides`], taking precedence over [`OVERRIDES`] on this thread
+    static SCOPED: RefCell<BTreeMap<String, String>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Parse a `name=value` parameter override
+pub fn parse_override(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_owned(), value.to_owned())),
+        _ => Err(format!(
+            "{arg} is not a valid parameter, expected name=value"
+        )),
+    }
+}
+
+/// Replace the values used instead of the parameter defaults,
+/// overrides for parameters a task doesn't declare are ignored for that task
+pub fn set_overrides(overrides: BTreeMap<String, String>) {
+    *OVERRIDES.write().unwrap_or_else(|err| err.into_inner()) = overrides;
+}
+
+/// Run `f` with additional overrides on the current thread only, e.g. the parameters of an example,
+/// so tasks run on other threads at the same time keep their values
+pub fn with_overrides<T>(overrides: &BTreeMap<String, String>, f: impl FnOnce() -> T) -> T {
+    let _restore = RestoreScoped(SCOPED.replace(overrides.clone()));
+    f()
+}
+
+struct Restore(Vec<(&'static str, String)>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CURRENT.set(std::mem::take(&mut self.0));
+    }
+}
+
+struct RestoreScoped(BTreeMap<String, String>);
+
+impl Drop for RestoreScoped {
+    fn drop(&mut self) {
+        SCOPED.set(std::mem::take(&mut self.0));
+    }
+}
+
+/// The values the parameters of the task currently have, with the overrides applied
+pub(crate) fn values(task: &Task) -> Vec<(&'static str, String)> {
+    let overrides = OVERRIDES.read().unwrap_or_else(|err| err.into_inner());
+    SCOPED.with_borrow(|scoped| {
+        task.params
+            .iter()
+            .map(|param| {
+                let value = scoped
+                    .get(param.name)
+                    .or_else(|| overrides.get(param.name))
+                    .map_or(param.default, String::as_str);
+                (param.name, value.to_owned())
+            })
+            .collect()
+    })
+}
+
+/// Run `f` with the parameters of the task available through [`param`]
+pub(crate) fn with_params<T>(task: &Task, f: impl FnOnce() -> T) -> T {
+    let _restore = Restore(CURRENT.replace(values(task)));
+    f()
+}
+
+/// The value of a parameter of the currently running task
+///
+/// Panics when the task doesn't declare the parameter or the value doesn't parse.
+pub fn param<T: FromStr>(name: &str) -> T
+where
+    T::Err: Display,
+{
+    CURRENT.with_borrow(|values| {
+        let (_, value) = values
+            .iter()
+            .find(|(param, _)| *param == name)
+            .unwrap_or_else(|| panic!("the running task has no parameter named {name}"));
+        value
+            .parse()
+            .unwrap_or_else(|err| panic!("invalid value {value} for parameter {name}: {err}"))
+    })
+}
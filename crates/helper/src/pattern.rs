@@ -0,0 +1,218 @@
+use std::ops::RangeInclusive;
+
+/// One segment of a task path pattern, a comma separated list of
+/// numbers (`8`, `08`, `day8`), ranges (`1..5`, `1..=5`) or plain names (`part1`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SegmentPattern {
+    items: Vec<Item>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    Range(RangeInclusive<u32>),
+    Name(String),
+}
+
+/// Parse a path segment as number, ignoring leading zeros and a `day` prefix
+pub fn segment_number(segment: &str) -> Option<u32> {
+    let digits = segment.strip_prefix("day").unwrap_or(segment);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+impl SegmentPattern {
+    pub fn parse(pattern: &str) -> Self {
+        let items = pattern
+            .split(',')
+            .map(|item| {
+                let range = if let Some((start, end)) = item.split_once("..=") {
+                    segment_number(start).zip(segment_number(end))
+                } else if let Some((start, end)) = item.split_once("..") {
+                    segment_number(start)
+                        .zip(segment_number(end))
+                        .and_then(|(start, end)| Some((start, end.checked_sub(1)?)))
+                } else {
+                    segment_number(item).map(|num| (num, num))
+                };
+                match range {
+                    Some((start, end)) => Item::Range(start..=end),
+                    None => Item::Name(item.to_owned()),
+                }
+            })
+            .collect();
+        Self { items }
+    }
+
+    pub fn matches(&self, segment: &str) -> bool {
+        let number = segment_number(segment);
+        self.items.iter().any(|item| match item {
+            Item::Range(range) => number.is_some_and(|number| range.contains(&number)),
+            Item::Name(name) => name == segment,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathPattern {
+    segments: Vec<SegmentPattern>,
+}
+
+impl PathPattern {
+    pub fn parse<S: AsRef<str>>(segments: &[S]) -> Self {
+        Self {
+            segments: segments
+                .iter()
+                .map(|segment| SegmentPattern::parse(segment.as_ref()))
+                .collect(),
+        }
+    }
+
+    /// Whether the pattern matches the start of the path
+    pub fn matches_prefix(&self, path: &[&str]) -> bool {
+        self.segments.len() <= path.len()
+            && self
+                .segments
+                .iter()
+                .zip(path)
+                .all(|(pattern, segment)| pattern.matches(segment))
+    }
+
+    /// Whether the pattern matches the whole path
+    pub fn matches(&self, path: &[&str]) -> bool {
+        self.segments.len() == path.len() && self.matches_prefix(path)
+    }
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// The path closest to the given arguments, compared segment wise and ignoring zero padding,
+/// when looking for a `prefix` only the start of each path is compared
+pub fn closest_path<'a, S: AsRef<str>>(
+    args: &[S],
+    paths: impl IntoIterator<Item = &'a [&'a str]>,
+    prefix: bool,
+) -> Option<&'a [&'a str]> {
+    let normalize = |segment: &str| match segment_number(segment) {
+        Some(number) => number.to_string(),
+        None => segment.to_owned(),
+    };
+    let args = args
+        .iter()
+        .map(|arg| normalize(arg.as_ref()))
+        .collect::<Vec<_>>();
+
+    paths.into_iter().min_by_key(|path| {
+        let len = if prefix { args.len() } else { path.len() };
+        let path = path
+            .iter()
+            .take(len)
+            .map(|segment| normalize(segment))
+            .collect::<Vec<_>>();
+        let common = args
+            .iter()
+            .zip(&path)
+            .map(|(arg, segment)| edit_distance(arg, segment))
+            .sum::<usize>();
+        let missing = args
+            .iter()
+            .skip(path.len())
+            .chain(path.iter().skip(args.len()))
+            .map(String::len)
+            .sum::<usize>();
+        common + missing
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segment_numbers() {
+        assert_eq!(segment_number("8"), Some(8));
+        assert_eq!(segment_number("08"), Some(8));
+        assert_eq!(segment_number("day8"), Some(8));
+        assert_eq!(segment_number("day08"), Some(8));
+        assert_eq!(segment_number("day"), None);
+        assert_eq!(segment_number("part1"), None);
+        assert_eq!(segment_number("-1"), None);
+    }
+
+    #[test]
+    fn numbers_and_names() {
+        let pattern = SegmentPattern::parse("day8,12,part1");
+        assert!(pattern.matches("8"));
+        assert!(pattern.matches("08"));
+        assert!(pattern.matches("12"));
+        assert!(pattern.matches("part1"));
+        assert!(!pattern.matches("9"));
+        assert!(!pattern.matches("part2"));
+    }
+
+    #[test]
+    fn ranges() {
+        let exclusive = SegmentPattern::parse("1..5");
+        assert!(exclusive.matches("01"));
+        assert!(exclusive.matches("4"));
+        assert!(!exclusive.matches("5"));
+        let inclusive = SegmentPattern::parse("1..=5");
+        assert!(inclusive.matches("5"));
+        assert!(!inclusive.matches("6"));
+        let padded = SegmentPattern::parse("day03..=07");
+        assert!(padded.matches("3"));
+        assert!(padded.matches("7"));
+    }
+
+    #[test]
+    fn empty_ranges() {
+        let empty = SegmentPattern::parse("1..1");
+        assert!(!empty.matches("0"));
+        assert!(!empty.matches("1"));
+        // the end can't be made inclusive, so `0..0` is taken as a name
+        let empty = SegmentPattern::parse("0..0");
+        assert!(!empty.matches("0"));
+        assert!(empty.matches("0..0"));
+        let single = SegmentPattern::parse("0..=0");
+        assert!(single.matches("0"));
+    }
+
+    #[test]
+    fn paths() {
+        let pattern = PathPattern::parse(&["2023", "day8"]);
+        assert!(pattern.matches_prefix(&["2023", "08", "part1"]));
+        assert!(!pattern.matches(&["2023", "08", "part1"]));
+        assert!(pattern.matches(&["2023", "08"]));
+        assert!(!pattern.matches_prefix(&["2023"]));
+    }
+
+    #[test]
+    fn closest() {
+        let paths: [&[&str]; 3] = [
+            &["2023", "08", "part1"],
+            &["2023", "08", "part2"],
+            &["2023", "18", "part1"],
+        ];
+        assert_eq!(
+            closest_path(&["2023", "day8", "prat2"], paths, false),
+            Some(paths[1])
+        );
+        assert_eq!(closest_path(&["2023", "19"], paths, true), Some(paths[2]));
+        assert_eq!(closest_path(&["2023", "8"], paths, true), Some(paths[0]));
+        assert_eq!(closest_path(&["2023"], [], true), None);
+    }
+}
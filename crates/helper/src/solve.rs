@@ -0,0 +1,45 @@
+use std::fmt::Display;
+
+use crate::{Answer, TASKS, Task};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// no linked year crate has a task for the part
+    UnknownPart { year: u16, day: u8, part: u8 },
+    /// the solver panicked, e.g. on malformed input
+    Panicked(String),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::UnknownPart { year, day, part } => {
+                write!(f, "no task {year} {day} part{part}")
+            }
+            SolveError::Panicked(msg) => write!(f, "the solver panicked: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// The task solving a part of a puzzle
+///
+/// Only the tasks of year crates linked into the binary are registered,
+/// reference them with e.g. `use aoc2023 as _;` to link them.
+pub fn find_task(year: u16, day: u8, part: u8) -> Option<&'static Task> {
+    let part = format!("part{part}");
+    TASKS.iter().find(|task| {
+        task.path.len() == 3 && task.path[2] == part && task.year_day() == Some((year, day))
+    })
+}
+
+/// Run the solver of a part on the given input, with its parameters at their defaults
+/// unless overridden through [`crate::params::set_overrides`] or [`crate::params::with_overrides`]
+///
+/// A panicking solver is reported as [`SolveError::Panicked`],
+/// the panic hook still reports the panic as usual.
+pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<Answer, SolveError> {
+    let task = find_task(year, day, part).ok_or(SolveError::UnknownPart { year, day, part })?;
+    task.try_solve(input).map_err(SolveError::Panicked)
+}
@@ -0,0 +1,90 @@
+//! Fingerprints the sources each day is built from, so cached answers are only reused
+//! by a runner built from the same day module, year crate, helper and dependencies
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use md5::Digest;
+
+fn entries(dir: &Path, filter: fn(&Path) -> bool) -> Vec<PathBuf> {
+    let mut entries = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| filter(path))
+        .collect::<Vec<_>>();
+    entries.sort();
+    entries
+}
+
+fn day_of(path: &Path) -> Option<u8> {
+    path.file_name()?
+        .to_str()?
+        .strip_prefix("day")?
+        .strip_suffix(".rs")?
+        .parse()
+        .ok()
+}
+
+fn hash_files<'a>(hasher: &mut md5::Md5, files: impl IntoIterator<Item = &'a PathBuf>) {
+    for file in files {
+        hasher.update(file.file_name().unwrap_or_default().as_encoded_bytes());
+        hasher.update(std::fs::read(file).unwrap_or_default());
+    }
+}
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let crates = root.join("crates");
+
+    let mut common = md5::Md5::new();
+    for file in [root.join("Cargo.toml"), root.join("Cargo.lock")] {
+        println!("cargo::rerun-if-changed={}", file.display());
+        hash_files(&mut common, [&file]);
+    }
+    let helper = crates.join("helper");
+    println!("cargo::rerun-if-changed={}", helper.display());
+    hash_files(&mut common, &[helper.join("Cargo.toml")]);
+    hash_files(&mut common, &entries(&helper.join("src"), Path::is_file));
+
+    let mut fingerprints = String::from("pub static FINGERPRINTS: &[(u16, u8, &str)] = &[\n");
+    for year_dir in entries(&crates, Path::is_dir) {
+        let Some(year) = year_dir
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("year")?.parse::<u16>().ok())
+        else {
+            continue;
+        };
+        println!("cargo::rerun-if-changed={}", year_dir.display());
+
+        let sources = entries(&year_dir.join("src"), Path::is_file);
+        let (days, shared): (Vec<_>, Vec<_>) =
+            sources.iter().partition(|path| day_of(path).is_some());
+        let mut year_hasher = common.clone();
+        hash_files(&mut year_hasher, &[year_dir.join("Cargo.toml")]);
+        hash_files(&mut year_hasher, shared);
+
+        for day in days {
+            let mut hasher = year_hasher.clone();
+            hash_files(&mut hasher, [day]);
+            let hash = hasher
+                .finalize()
+                .iter()
+                .fold(String::new(), |mut hex, byte| {
+                    _ = write!(hex, "{byte:02x}");
+                    hex
+                });
+            _ = writeln!(
+                fingerprints,
+                "    ({year}, {}, \"{hash}\"),",
+                day_of(day).unwrap_or_default()
+            );
+        }
+    }
+    fingerprints.push_str("];\n");
+
+    let out = PathBuf::from(std::env::var_os("OUT_DIR").expect("cargo sets OUT_DIR"));
+    std::fs::write(out.join("fingerprints.rs"), fingerprints)
+        .expect("failed to write fingerprints");
+}
@@ -0,0 +1,216 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use helper::alloc::AllocStats;
+use helper::{TagFilter, Task};
+
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub runs: usize,
+    /// the file the results are appended to
+    pub history: PathBuf,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            runs: 10,
+            history: PathBuf::from(crate::perf::DEFAULT_HISTORY),
+        }
+    }
+}
+
+struct BenchResult {
+    task: &'static Task,
+    input_hash: String,
+    /// reading the input file
+    read: Duration,
+    /// the median of parsing the input alone, for tasks with a registered parser
+    parse: Option<Duration>,
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    max: Duration,
+    memory: Option<AllocStats>,
+}
+
+impl BenchResult {
+    fn from_samples(
+        task: &'static Task,
+        input_hash: String,
+        read: Duration,
+        parse: Option<Duration>,
+        mut samples: Vec<Duration>,
+        memory: Option<AllocStats>,
+    ) -> Self {
+        samples.sort();
+        Self {
+            task,
+            input_hash,
+            read,
+            parse,
+            min: samples[0],
+            median: median(samples.clone()),
+            mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+            max: samples[samples.len() - 1],
+            memory,
+        }
+    }
+}
+
+pub fn median(mut samples: Vec<Duration>) -> Duration {
+    samples.sort();
+    if samples.len().is_multiple_of(2) {
+        (samples[samples.len() / 2 - 1] + samples[samples.len() / 2]) / 2
+    } else {
+        samples[samples.len() / 2]
+    }
+}
+
+pub fn bench_prefix<S: AsRef<str>>(
+    prefix: &[S],
+    inputs_root: &Path,
+    options: &BenchOptions,
+    filter: &TagFilter,
+) {
+    let runs = options.runs.max(1);
+    let mut results = vec![];
+
+    for task in helper::tasks_filtered_by_prefix(prefix).filter(|task| filter.selects(task)) {
+        // tagged tasks are only benched when their tags or the task are selected explicitly
+        if !filter.runs(task) && task.path.len() != prefix.len() {
+            println!("Skipping {}", task.path.join(" "));
+            continue;
+        }
+
+        println!("Benching {}", task.path.join(" "));
+
+        let start = Instant::now();
+        let input = match task.load_input(inputs_root) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("Failed {}: {err}", task.path.join(" "));
+                continue;
+            }
+        };
+        let read = start.elapsed();
+
+        // a panicking task is reported and left out of the results, like `all` does
+        let samples = (0..options.warmup + runs)
+            .map(|_| {
+                let start = Instant::now();
+                std::hint::black_box(task.try_solve(&input))?;
+                Ok(start.elapsed())
+            })
+            .collect::<Result<Vec<_>, String>>();
+        let samples = match samples {
+            Ok(samples) => samples[options.warmup..].to_vec(),
+            Err(msg) => {
+                println!("Panicked {}: {msg}", task.path.join(" "));
+                continue;
+            }
+        };
+
+        // parsing is timed on its own after the whole runs, which include it
+        let parse = task.parser().and_then(|parser| {
+            let samples = (0..options.warmup + runs)
+                .map(|_| {
+                    let start = Instant::now();
+                    std::panic::catch_unwind(|| task.run_parser(parser, &input)).ok()?;
+                    Some(start.elapsed())
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(median(samples[options.warmup..].to_vec()))
+        });
+
+        // counted in a separate run, so the counting doesn't affect the timings
+        let memory = if helper::alloc::is_active() {
+            helper::alloc::measure(|| task.solve(&input)).1
+        } else {
+            None
+        };
+
+        let input_hash = helper::cache::input_hash(&input);
+        results.push(BenchResult::from_samples(
+            task, input_hash, read, parse, samples, memory,
+        ));
+    }
+
+    results.sort_by_key(|result| std::cmp::Reverse(result.median));
+    print_results(&results, runs);
+
+    let commit = crate::perf::current_commit();
+    let timestamp = crate::perf::now();
+    let records = results
+        .iter()
+        .map(|result| crate::perf::Record {
+            path: result.task.path.join(" "),
+            commit: commit.clone(),
+            input: result.input_hash.clone(),
+            median: result.median,
+            timestamp,
+        })
+        .collect::<Vec<_>>();
+    match crate::perf::append(&options.history, &records) {
+        Ok(()) => println!(
+            "Recorded {} results for {commit} in {}",
+            records.len(),
+            options.history.display()
+        ),
+        Err(err) => println!(
+            "failed to record results in {}: {err}",
+            options.history.display()
+        ),
+    }
+}
+
+fn print_results(results: &[BenchResult], runs: usize) {
+    let width = results
+        .iter()
+        .map(|result| result.task.path.join(" ").len())
+        .chain([4])
+        .max()
+        .unwrap_or_default();
+
+    println!();
+    println!("Results over {runs} runs, sorted by median:");
+    let memory = results.iter().any(|result| result.memory.is_some());
+    print!(
+        "{:<width$} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12}",
+        "task", "read", "parse", "solve", "min", "median", "mean", "max"
+    );
+    if memory {
+        print!(" {:>12} {:>12} {:>12}", "peak", "allocations", "allocated");
+    }
+    println!();
+    for result in results {
+        let (parse, solve) = match result.parse {
+            Some(parse) => (
+                format!("{parse:.2?}"),
+                format!("{:.2?}", result.median.saturating_sub(parse)),
+            ),
+            None => (String::new(), String::new()),
+        };
+        print!(
+            "{:<width$} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12}",
+            result.task.path.join(" "),
+            format!("{:.2?}", result.read),
+            parse,
+            solve,
+            format!("{:.2?}", result.min),
+            format!("{:.2?}", result.median),
+            format!("{:.2?}", result.mean),
+            format!("{:.2?}", result.max),
+        );
+        if let Some(stats) = result.memory {
+            print!(
+                " {:>12} {:>12} {:>12}",
+                stats.peak_display(),
+                stats.allocations,
+                stats.allocated_display()
+            );
+        }
+        println!();
+    }
+}
@@ -0,0 +1,383 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use helper::guesses::{Guess, GuessVerdict};
+
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_REQUEST_INTERVAL: f64 = 5.0;
+const USER_AGENT: &str = concat!(
+    "runner/",
+    env!("CARGO_PKG_VERSION"),
+    " (Advent of Code solutions, fetching inputs and submitting answers on request)"
+);
+/// when the last request was sent, shared between runner invocations to rate-limit them
+const LAST_REQUEST: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../target/aoc-last-request");
+
+/// The session and server to talk to, stored as
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// # optional, e.g. to test against a local server
+/// base_url = "https://adventofcode.com"
+/// # optional, the minimal number of seconds between two requests
+/// request_interval = 5
+/// ```
+pub struct Config {
+    session: String,
+    base_url: String,
+    request_interval: Duration,
+}
+
+/// `$AOC_CONFIG`, or `aoc/config.toml` in `$XDG_CONFIG_HOME` or `~/.config`
+pub fn config_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(CONFIG_ENV) {
+        return Some(PathBuf::from(path));
+    }
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config_home.join("aoc/config.toml"))
+}
+
+impl Config {
+    pub fn load() -> Result<Self, String> {
+        let path = config_path().ok_or("no config file, set $AOC_CONFIG")?;
+        let content = std::fs::read_to_string(&path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+        Self::parse(&content).map_err(|err| format!("failed to parse {}: {err}", path.display()))
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        let table = content
+            .parse::<toml::Table>()
+            .map_err(|err| err.to_string())?;
+        let session = match table.get("session") {
+            Some(toml::Value::String(session)) if !session.is_empty() => session.clone(),
+            _ => return Err("session is missing or not a string".to_owned()),
+        };
+        let base_url = match table.get("base_url") {
+            None => DEFAULT_BASE_URL.to_owned(),
+            Some(toml::Value::String(url)) => url.trim_end_matches('/').to_owned(),
+            Some(_) => return Err("base_url is not a string".to_owned()),
+        };
+        let request_interval = match table.get("request_interval") {
+            None => DEFAULT_REQUEST_INTERVAL,
+            Some(toml::Value::Integer(secs)) => *secs as f64,
+            Some(toml::Value::Float(secs)) => *secs,
+            Some(_) => return Err("request_interval is not a number".to_owned()),
+        };
+        let request_interval = Duration::try_from_secs_f64(request_interval)
+            .map_err(|err| format!("request_interval is invalid: {err}"))?;
+        Ok(Self {
+            session,
+            base_url,
+            request_interval,
+        })
+    }
+
+    /// Wait until the request interval passed since the last request of any runner
+    fn rate_limit(&self) {
+        let last = std::fs::metadata(LAST_REQUEST).and_then(|metadata| metadata.modified());
+        if let Ok(last) = last
+            && let Ok(elapsed) = SystemTime::now().duration_since(last)
+            && let Some(remaining) = self.request_interval.checked_sub(elapsed)
+        {
+            println!("Waiting {remaining:.1?} before the next request");
+            std::thread::sleep(remaining);
+        }
+        if let Some(parent) = Path::new(LAST_REQUEST).parent() {
+            _ = std::fs::create_dir_all(parent);
+        }
+        _ = std::fs::write(LAST_REQUEST, b"");
+    }
+
+    fn agent(&self) -> ureq::Agent {
+        ureq::Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .http_status_as_error(false)
+            .build()
+            .into()
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn read_body(
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<String, String> {
+    let mut response = response.map_err(|err| format!("request failed: {err}"))?;
+    let status = response.status();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|err| format!("failed to read the response: {err}"))?;
+    if status.is_success() {
+        Ok(body)
+    } else {
+        Err(format!("request failed with {status}: {}", body.trim()))
+    }
+}
+
+/// Download the personal input of a day into the inputs, an existing non-empty input is kept
+pub fn fetch(inputs_root: &Path, year: u16, day: u8) -> Result<(), String> {
+    let path = helper::input::personal_input_path(inputs_root, year, day);
+    if std::fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+        println!("{} already exists", path.display());
+        return Ok(());
+    }
+
+    let config = Config::load()?;
+    config.rate_limit();
+    let url = format!("{}/{year}/day/{day}/input", config.base_url);
+    let input = read_body(
+        config
+            .agent()
+            .get(&url)
+            .header("Cookie", config.cookie())
+            .call(),
+    )?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create {}: {err}", parent.display()))?;
+    }
+    std::fs::write(&path, input)
+        .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    println!("Fetched {}", path.display());
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    Correct,
+    Incorrect(GuessVerdict),
+    /// an answer was submitted too recently, with the message telling how long to wait
+    Wait(String),
+    /// the part was already solved or is not unlocked yet
+    WrongLevel,
+    Unknown(String),
+}
+
+/// The text of the response's article, without the markup
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub fn parse_response(html: &str) -> Response {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Response::Correct
+    } else if text.contains("That's not the right answer") {
+        Response::Incorrect(if text.contains("your answer is too high") {
+            GuessVerdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            GuessVerdict::TooLow
+        } else {
+            GuessVerdict::Wrong
+        })
+    } else if text.contains("You gave an answer too recently") {
+        Response::Wait(text)
+    } else if text.contains("You don't seem to be solving the right level") {
+        Response::WrongLevel
+    } else {
+        Response::Unknown(text)
+    }
+}
+
+/// Submit an answer for a part, recording wrong answers as guesses and the right one as known answer,
+/// returns whether the answer was right
+pub fn submit(
+    inputs_root: &Path,
+    year: u16,
+    day: u8,
+    part: &str,
+    answer: &str,
+) -> Result<bool, String> {
+    let level = part
+        .strip_prefix("part")
+        .filter(|level| matches!(*level, "1" | "2"))
+        .ok_or_else(|| format!("{part} can't be submitted, expected part1 or part2"))?;
+
+    let answers =
+        helper::answers::Answers::load(inputs_root, year).map_err(|err| err.to_string())?;
+    if let Some(expected) = answers.expected(day, part) {
+        println!("The answer of {year} {day} {part} is already known to be {expected}");
+        return Ok(expected == answer);
+    }
+    let guesses =
+        helper::guesses::Guesses::load(inputs_root, year).map_err(|err| err.to_string())?;
+    let warnings = guesses.check(day, part, &helper::Answer::Text(answer.to_owned()));
+    if !warnings.is_empty() {
+        return Err(format!("not submitting {answer}: {}", warnings.join(", ")));
+    }
+
+    let config = Config::load()?;
+    config.rate_limit();
+    let url = format!("{}/{year}/day/{day}/answer", config.base_url);
+    let html = read_body(
+        config
+            .agent()
+            .post(&url)
+            .header("Cookie", config.cookie())
+            .send_form([("level", level), ("answer", answer)]),
+    )?;
+
+    match parse_response(&html) {
+        Response::Correct => {
+            println!("{answer} is the right answer for {year} {day} {part}");
+            helper::answers::record_answer(inputs_root, year, day, part, answer)
+                .map_err(|err| format!("failed to record the answer: {err}"))?;
+            Ok(true)
+        }
+        Response::Incorrect(verdict) => {
+            println!("{answer} is {verdict} for {year} {day} {part}");
+            let guess = Guess {
+                answer: answer.to_owned(),
+                verdict: Some(verdict),
+            };
+            helper::guesses::record_guess(inputs_root, year, day, part, &guess)
+                .map_err(|err| format!("failed to record the guess: {err}"))?;
+            Ok(false)
+        }
+        Response::Wait(message) => Err(message),
+        Response::WrongLevel => Err(format!(
+            "{year} {day} {part} is already solved or not unlocked yet"
+        )),
+        Response::Unknown(message) => Err(format!("unexpected response: {message}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html><head><title>Day 1 - Advent of Code</title></head><body>\n\
+             <main>\n<article><p>{article}</p></article>\n</main></body></html>"
+        )
+    }
+
+    #[test]
+    fn right_answer() {
+        let html = page(
+            "That's the right answer!  You are <em>one gold star</em> closer to saving Christmas. \
+             <a href=\"/2023/day/1#part2\">[Continue to Part Two]</a>",
+        );
+        assert_eq!(parse_response(&html), Response::Correct);
+    }
+
+    #[test]
+    fn too_high() {
+        let html = page(
+            "That's not the right answer; your answer is too high.  If you're stuck, make sure \
+             you're using the full input data; there are also some general tips on the \
+             <a href=\"/2023/about\">about page</a>. Please wait one minute before trying again. \
+             <a href=\"/2023/day/1\">[Return to Day 1]</a>",
+        );
+        assert_eq!(
+            parse_response(&html),
+            Response::Incorrect(GuessVerdict::TooHigh)
+        );
+    }
+
+    #[test]
+    fn too_low() {
+        let html = page(
+            "That's not the right answer; your answer is too low.  Please wait one minute before \
+             trying again. <a href=\"/2023/day/1\">[Return to Day 1]</a>",
+        );
+        assert_eq!(
+            parse_response(&html),
+            Response::Incorrect(GuessVerdict::TooLow)
+        );
+    }
+
+    #[test]
+    fn wrong_without_hint() {
+        let html = page(
+            "That's not the right answer.  If you're stuck, make sure you're using the full input \
+             data. <a href=\"/2023/day/1\">[Return to Day 1]</a>",
+        );
+        assert_eq!(
+            parse_response(&html),
+            Response::Incorrect(GuessVerdict::Wrong)
+        );
+    }
+
+    #[test]
+    fn answered_too_recently() {
+        let html = page(
+            "You gave an answer too recently; you have to wait after submitting an answer before \
+             trying again.  You have 38s left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a>",
+        );
+        assert_eq!(
+            parse_response(&html),
+            Response::Wait(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again. You have 38s left to wait. [Return to Day 1]"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn wrong_level() {
+        let html = page(
+            "You don't seem to be solving the right level.  Did you already complete it? \
+             <a href=\"/2023/day/1\">[Return to Day 1]</a>",
+        );
+        assert_eq!(parse_response(&html), Response::WrongLevel);
+    }
+
+    #[test]
+    fn config_defaults() {
+        let config = Config::parse("session = \"abc\"").unwrap();
+        assert_eq!(config.session, "abc");
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert_eq!(
+            config.request_interval,
+            Duration::from_secs_f64(DEFAULT_REQUEST_INTERVAL)
+        );
+    }
+
+    #[test]
+    fn config_values() {
+        let config = Config::parse(
+            "session = \"abc\"\nbase_url = \"http://localhost:8000/\"\nrequest_interval = 0.5",
+        )
+        .unwrap();
+        assert_eq!(config.base_url, "http://localhost:8000");
+        assert_eq!(config.request_interval, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn config_errors() {
+        let error = |content| Config::parse(content).err().unwrap();
+        assert!(error("base_url = \"http://localhost\"").starts_with("session"));
+        assert!(error("session = \"\"").starts_with("session"));
+        assert!(error("session = \"abc\"\nrequest_interval = -1").starts_with("request_interval"));
+        assert!(error("session = \"abc\"\nrequest_interval = nan").starts_with("request_interval"));
+        assert!(
+            error("session = \"abc\"\nrequest_interval = \"5\"").starts_with("request_interval")
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::BTreeSet;
+
+use helper::pattern::segment_number;
+use helper::{TASKS, Tag};
+
+/// the hidden command the completion scripts call with the words typed so far
+pub const COMPLETE_COMMAND: &str = "__complete";
+pub const SHELLS: [&str; 3] = ["bash", "zsh", "fish"];
+
+/// the options that take a value, their value is the next argument
+const VALUE_OPTIONS: [&str; 16] = [
+    "--inputs",
+    "--port",
+    "--warmup",
+    "--runs",
+    "--jobs",
+    "--timeout",
+    "--format",
+    "--history",
+    "--threshold",
+    "--baseline",
+    "--tag",
+    "--exclude-tag",
+    "--param",
+    "--input",
+    "--example",
+    "--profile",
+];
+const FLAGS: [&str; 3] = ["--write", "--cached", "--all-profiles"];
+
+/// the commands following a task prefix
+const PREFIX_COMMANDS: [&str; 12] = [
+    "list",
+    "all",
+    "bench",
+    "perf",
+    "verify",
+    "crosscheck",
+    "report",
+    "watch",
+    "generate",
+    "fetch",
+    "guess",
+    "submit",
+];
+/// the commands only valid on their own
+const COMMANDS: [&str; 3] = ["help", "serve", "completions"];
+/// the commands after which no further task path or command follows
+const FINAL_COMMANDS: [&str; 15] = [
+    "list",
+    "all",
+    "bench",
+    "diff",
+    "verify",
+    "crosscheck",
+    "report",
+    "watch",
+    "generate",
+    "fetch",
+    "guess",
+    "submit",
+    "serve",
+    "help",
+    "completions",
+];
+
+const BASH: &str = r#"_runner() {
+    local IFS=$'\n'
+    COMPREPLY=($(runner __complete "${COMP_WORDS[@]:1:COMP_CWORD}" 2>/dev/null))
+}
+complete -o default -F _runner runner
+"#;
+
+const ZSH: &str = r#"#compdef runner
+_runner() {
+    local -a candidates
+    candidates=(${(f)"$(runner __complete "${(@)words[2,CURRENT]}" 2>/dev/null)"})
+    if (( ${#candidates} )); then
+        compadd -a candidates
+    else
+        _files
+    fi
+}
+compdef _runner runner
+"#;
+
+const FISH: &str = r#"function __runner_complete
+    runner __complete (commandline -opc)[2..-1] (commandline -ct) 2>/dev/null
+end
+complete -c runner -f -a '(__runner_complete)'
+"#;
+
+/// The completion script for the shell, it asks the runner for the candidates,
+/// so the tasks of new days are completed without regenerating it
+pub fn script(shell: &str) -> Option<&'static str> {
+    match shell {
+        "bash" => Some(BASH),
+        "zsh" => Some(ZSH),
+        "fish" => Some(FISH),
+        _ => None,
+    }
+}
+
+fn option_values(option: &str) -> Vec<String> {
+    match option {
+        "--tag" | "--exclude-tag" => Tag::ALL.iter().map(|tag| tag.name().to_owned()).collect(),
+        "--format" => ["text", "json", "csv"].map(str::to_owned).to_vec(),
+        "--profile" => helper::input::profiles(&helper::input::inputs_root()),
+        "--param" => TASKS
+            .iter()
+            .flat_map(|task| task.params)
+            .map(|param| format!("{}=", param.name))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect(),
+        // files and numbers are left to the shell
+        _ => vec![],
+    }
+}
+
+/// Whether a typed word names the path segment, ignoring zero padding and a `day` prefix like task patterns do
+fn names_segment(word: &str, segment: &str) -> bool {
+    word == segment
+        || segment_number(word).is_some_and(|number| segment_number(segment) == Some(number))
+}
+
+fn candidates(words: &[String], current: &str) -> Vec<String> {
+    if let Some(option) = words.last()
+        && VALUE_OPTIONS.contains(&option.as_str())
+    {
+        return option_values(option);
+    }
+    if current.starts_with('-') {
+        return VALUE_OPTIONS
+            .iter()
+            .chain(&FLAGS)
+            .map(|option| (*option).to_owned())
+            .collect();
+    }
+
+    let mut positional = vec![];
+    let mut words = words.iter();
+    while let Some(word) = words.next() {
+        if VALUE_OPTIONS.contains(&word.as_str()) {
+            words.next();
+        } else if !word.starts_with('-') {
+            positional.push(word.as_str());
+        }
+    }
+
+    match positional.as_slice() {
+        [.., "completions"] => return SHELLS.map(str::to_owned).to_vec(),
+        [.., "perf"] => return vec!["diff".to_owned()],
+        _ => {}
+    }
+    if positional.iter().any(|word| FINAL_COMMANDS.contains(word)) {
+        return vec![];
+    }
+
+    // the next segments of the task paths starting with the words typed so far
+    let mut candidates = TASKS
+        .iter()
+        .filter(|task| {
+            task.path.len() > positional.len()
+                && positional
+                    .iter()
+                    .zip(task.path)
+                    .all(|(word, segment)| names_segment(word, segment))
+        })
+        .map(|task| task.path[positional.len()])
+        .chain(PREFIX_COMMANDS)
+        .collect::<BTreeSet<_>>();
+    if positional.is_empty() {
+        candidates.extend(COMMANDS);
+    }
+    candidates.into_iter().map(str::to_owned).collect()
+}
+
+/// Print the candidates for the last of the words, one per line
+pub fn complete(words: &[String]) {
+    let Some((current, words)) = words.split_last() else {
+        return;
+    };
+    for candidate in candidates(words, current) {
+        if candidate.starts_with(current.as_str()) || names_segment(current, &candidate) {
+            println!("{candidate}");
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use helper::{Answer, TagFilter};
+
+use crate::bench::{BenchOptions, median};
+
+struct VariantResult {
+    name: &'static str,
+    answer: Answer,
+    median: Duration,
+}
+
+/// Time the runs, a panic is caught and returned as its message
+fn time_runs(
+    options: &BenchOptions,
+    mut run: impl FnMut() -> Answer,
+) -> Result<(Answer, Duration), String> {
+    let mut run = || {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(&mut run))
+            .map_err(|payload| helper::panic_message(payload.as_ref()))
+    };
+    for _ in 0..options.warmup {
+        std::hint::black_box(run()?);
+    }
+    let mut answer = Answer::Unit;
+    let samples = (0..options.runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            answer = std::hint::black_box(run()?);
+            Ok(start.elapsed())
+        })
+        .collect::<Result<_, String>>()?;
+    Ok((answer, median(samples)))
+}
+
+/// Run every task with registered variants together with its variants on the same input,
+/// returns whether all variants agreed with their task
+pub fn crosscheck_prefix<S: AsRef<str>>(
+    prefix: &[S],
+    inputs_root: &Path,
+    options: &BenchOptions,
+    filter: &TagFilter,
+) -> bool {
+    let mut found = false;
+    let mut disagreements = vec![];
+
+    for task in helper::tasks_filtered_by_prefix(prefix).filter(|task| filter.selects(task)) {
+        let variants = task.variants().collect::<Vec<_>>();
+        if variants.is_empty() {
+            continue;
+        }
+        found = true;
+
+        let input = match task.load_input(inputs_root) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("Failed {}: {err}", task.path.join(" "));
+                continue;
+            }
+        };
+        println!("Crosschecking {}", task.path.join(" "));
+
+        let (answer, median) = match time_runs(options, || task.solve(&input)) {
+            Ok(result) => result,
+            Err(msg) => {
+                println!("Panicked {}: {msg}", task.path.join(" "));
+                disagreements.push(format!("{}: panicked: {msg}", task.path.join(" ")));
+                continue;
+            }
+        };
+        let mut results = vec![VariantResult {
+            name: "main",
+            answer,
+            median,
+        }];
+        for variant in variants {
+            match time_runs(options, || task.solve_variant(variant, &input)) {
+                Ok((answer, median)) => results.push(VariantResult {
+                    name: variant.name,
+                    answer,
+                    median,
+                }),
+                Err(msg) => {
+                    println!("\t{} panicked: {msg}", variant.name);
+                    disagreements.push(format!(
+                        "{} {}: panicked: {msg}",
+                        task.path.join(" "),
+                        variant.name
+                    ));
+                }
+            }
+        }
+
+        let expected = results[0].answer.to_string();
+        let fastest = results
+            .iter()
+            .map(|result| result.median)
+            .min()
+            .unwrap_or_default()
+            .max(Duration::from_nanos(1));
+        let width = results
+            .iter()
+            .map(|result| result.name.len())
+            .max()
+            .unwrap_or_default();
+        for result in &results {
+            let actual = result.answer.to_string();
+            let agrees = actual == expected;
+            if !agrees {
+                disagreements.push(format!(
+                    "{} {}: expected {expected}, got {actual}",
+                    task.path.join(" "),
+                    result.name
+                ));
+            }
+            println!(
+                "\t{:<width$} {:>12} {:>8} {}",
+                result.name,
+                format!("{:.2?}", result.median),
+                format!(
+                    "{:.2}x",
+                    result.median.as_secs_f64() / fastest.as_secs_f64()
+                ),
+                if agrees { "ok" } else { "DIFFERS" },
+            );
+        }
+    }
+
+    if !found {
+        println!("No task with variants matches the prefix");
+    }
+    for disagreement in &disagreements {
+        println!("{disagreement}");
+    }
+    disagreements.is_empty()
+}
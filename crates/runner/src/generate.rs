@@ -0,0 +1,196 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+pub const WORKSPACE_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../..");
+
+enum Change {
+    Create(PathBuf, String),
+    Update(PathBuf, String),
+}
+
+/// The files to create and update, planned before anything is written
+#[derive(Default)]
+struct Plan {
+    changes: Vec<Change>,
+    existing: Vec<PathBuf>,
+}
+
+impl Plan {
+    fn create(&mut self, path: PathBuf, content: String) {
+        if path.exists() {
+            self.existing.push(path);
+        } else {
+            self.changes.push(Change::Create(path, content));
+        }
+    }
+
+    /// Insert `line` into the file next to the lines it belongs with, unless it is already present
+    fn insert_line(
+        &mut self,
+        path: PathBuf,
+        line: &str,
+        sibling: impl Fn(&str) -> bool,
+    ) -> Result<(), String> {
+        let content = std::fs::read_to_string(&path)
+            .map_err(|err| format!("failed to read {}: {err}", display(&path)))?;
+        if content.lines().any(|existing| existing.trim() == line) {
+            return Ok(());
+        }
+
+        let lines = content.lines().collect::<Vec<_>>();
+        let siblings = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, existing)| sibling(existing))
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+        let Some(&last) = siblings.last() else {
+            return Err(format!(
+                "could not find where to add `{line}` to {}",
+                display(&path)
+            ));
+        };
+        let idx = siblings
+            .iter()
+            .copied()
+            .find(|&idx| lines[idx] > line)
+            .unwrap_or(last + 1);
+
+        let mut updated = lines[..idx].join("\n");
+        if idx > 0 {
+            updated.push('\n');
+        }
+        updated.push_str(line);
+        for rest in &lines[idx..] {
+            updated.push('\n');
+            updated.push_str(rest);
+        }
+        if content.ends_with('\n') {
+            updated.push('\n');
+        }
+
+        self.changes.push(Change::Update(path, updated));
+        Ok(())
+    }
+}
+
+fn display(path: &Path) -> String {
+    path.strip_prefix(WORKSPACE_ROOT)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+fn template(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("DD", &format!("{day:02}"))
+        .replace("YYYY", &format!("{year}"))
+}
+
+/// Scaffold the module and inputs for a day, creating the year crate if it doesn't exist yet
+///
+/// Everything is validated and planned before the first file is written,
+/// files that already exist are left untouched and reported.
+pub fn generate(year: u16, day: u8, inputs_root: &Path) -> Result<(), String> {
+    if year < 2015 {
+        return Err(format!("{year} is before the first Advent of Code in 2015"));
+    }
+    if !(1..=25).contains(&day) {
+        return Err(format!("{day} is not a day between 1 and 25"));
+    }
+
+    let root = Path::new(WORKSPACE_ROOT);
+    let crate_dir = root.join(format!("crates/year{year}"));
+    let mut plan = Plan::default();
+
+    plan.create(
+        crate_dir.join("Cargo.toml"),
+        template(include_str!("../template/year-Cargo.toml"), year, day),
+    );
+    plan.create(
+        crate_dir.join("README.md"),
+        template(include_str!("../template/year-README.md"), year, day),
+    );
+
+    let lib = crate_dir.join("src/lib.rs");
+    if lib.exists() {
+        plan.insert_line(lib, &format!("pub mod day{day:02};"), |line| {
+            line.starts_with("pub mod day")
+        })?;
+    } else {
+        plan.create(
+            lib,
+            template(include_str!("../template/year-lib.rs"), year, day),
+        );
+    }
+
+    plan.create(
+        crate_dir.join("tests/examples.rs"),
+        template(include_str!("../template/year-examples.rs"), year, day),
+    );
+    plan.create(
+        crate_dir.join(format!("src/day{day:02}.rs")),
+        template(include_str!("../template/lib-mod.rs"), year, day),
+    );
+    plan.create(
+        inputs_root.join(format!("example/year{year}/day{day:02}.example1.txt")),
+        String::new(),
+    );
+    plan.create(
+        inputs_root.join(format!("example/year{year}/day{day:02}.example1.answers")),
+        include_str!("../template/example.answers").to_owned(),
+    );
+    plan.create(
+        helper::input::personal_input_path(inputs_root, year, day),
+        String::new(),
+    );
+
+    plan.insert_line(
+        root.join("Cargo.toml"),
+        &format!("aoc{year} = {{ path = \"./crates/year{year}\" }}"),
+        |line| line.starts_with("aoc"),
+    )?;
+    plan.insert_line(
+        root.join("crates/runner/Cargo.toml"),
+        &format!("aoc{year} = {{ workspace = true }}"),
+        |line| line.starts_with("aoc"),
+    )?;
+    plan.insert_line(
+        root.join("crates/runner/src/main.rs"),
+        &format!("use aoc{year} as _;"),
+        |line| line.starts_with("use aoc"),
+    )?;
+
+    for path in &plan.existing {
+        println!("{} already exists", display(path));
+    }
+    if plan.changes.is_empty() {
+        println!("Nothing to generate for {year} day {day}");
+        return Ok(());
+    }
+
+    for change in plan.changes {
+        match change {
+            Change::Create(path, content) => {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)
+                        .map_err(|err| format!("failed to create {}: {err}", display(parent)))?;
+                }
+                std::fs::OpenOptions::new()
+                    .write(true)
+                    .create_new(true)
+                    .open(&path)
+                    .and_then(|mut file| file.write_all(content.as_bytes()))
+                    .map_err(|err| format!("failed to create {}: {err}", display(&path)))?;
+                println!("Created {}", display(&path));
+            }
+            Change::Update(path, content) => {
+                std::fs::write(&path, content)
+                    .map_err(|err| format!("failed to update {}: {err}", display(&path)))?;
+                println!("Updated {}", display(&path));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -4,22 +4,230 @@ use aoc2022 as _;
 use aoc2023 as _;
 use aoc2024 as _;
 use aoc2025 as _;
-use helper::{TASKS, Task};
+use helper::guesses::{Guess, GuessVerdict};
+use helper::input::InputSource;
+use helper::output::OutputFormat;
+use helper::{Answer, TASKS, Tag, TagFilter, Task};
 use linkme::distributed_slice;
-use std::io::Write;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::num::ParseFloatError;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: helper::alloc::CountingAllocator = helper::alloc::CountingAllocator;
+
+// the source fingerprints of every day, generated by the build script
+include!(concat!(env!("OUT_DIR"), "/fingerprints.rs"));
+
+const CACHE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../target/answer-cache");
+
+mod bench;
+mod client;
+mod completions;
+mod crosscheck;
+mod generate;
+mod perf;
+mod report;
+mod serve;
+mod verify;
+mod watch;
 
 fn main() {
     env_logger::init();
 
-    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    // before the options are taken, as the words to complete may contain incomplete ones
+    if args
+        .first()
+        .is_some_and(|arg| arg == completions::COMPLETE_COMMAND)
+    {
+        completions::complete(&args[1..]);
+        return;
+    }
+
+    let inputs_root = match take_option(&mut args, "--inputs") {
+        Ok(Some(root)) => PathBuf::from(root),
+        Ok(None) => helper::input::inputs_root(),
+        Err(err) => {
+            println!("{err}");
+            std::process::exit(1);
+        }
+    };
+
+    let mut bench_options = bench::BenchOptions::default();
+    let mut diff_options = perf::DiffOptions::default();
+    if let Err(err) = take_perf_options(&mut args, &mut bench_options, &mut diff_options) {
+        println!("{err}");
+        std::process::exit(1);
+    }
+    let mut run_options = helper::RunOptions::default();
+    if let Err(err) = take_numeric_options(&mut args, &mut bench_options, &mut run_options) {
+        println!("{err}");
+        std::process::exit(1);
+    }
+    match take_tag_filter(&mut args) {
+        Ok(filter) => run_options.filter = filter,
+        Err(err) => {
+            println!("{err}");
+            std::process::exit(1);
+        }
+    }
+    match take_params(&mut args) {
+        Ok(overrides) => helper::params::set_overrides(overrides),
+        Err(err) => {
+            println!("{err}");
+            std::process::exit(1);
+        }
+    }
+    match take_input_source(&mut args) {
+        Ok(source) => helper::input::set_input_source(source),
+        Err(err) => {
+            println!("{err}");
+            std::process::exit(1);
+        }
+    }
+    let profiles = match take_profiles(&mut args, &inputs_root) {
+        Ok(profiles) => profiles,
+        Err(err) => {
+            println!("{err}");
+            std::process::exit(1);
+        }
+    };
+
+    let port = match take_parsed_option(&mut args, "--port") {
+        Ok(port) => port.unwrap_or(serve::DEFAULT_PORT),
+        Err(err) => {
+            println!("{err}");
+            std::process::exit(1);
+        }
+    };
+    let write_report = take_flag(&mut args, "--write");
+    if take_flag(&mut args, "--cached") {
+        helper::cache::set_cache(Some(helper::cache::Cache {
+            dir: PathBuf::from(CACHE_DIR),
+            fingerprints: FINGERPRINTS,
+        }));
+    }
+
+    if let Some(idx) = args.iter().position(|arg| arg == "guess") {
+        record_guess(&args[..idx], &args[idx + 1..], &inputs_root);
+        return;
+    }
+
+    if let Some(idx) = args.iter().position(|arg| arg == "completions") {
+        match (&args[..idx], &args[idx + 1..]) {
+            ([], [shell]) if let Some(script) = completions::script(shell) => print!("{script}"),
+            _ => {
+                println!(
+                    "Completions takes exactly one of {} after it!",
+                    completions::SHELLS.join(", ")
+                );
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if let Some(idx) = args.iter().position(|arg| arg == "submit") {
+        if !submit(&args[..idx], &args[idx + 1..], &inputs_root) {
+            std::process::exit(1);
+        }
+        return;
+    }
 
     if let Some((last, prefix)) = args.split_last() {
         match last.as_str() {
             "list" => {
-                helper::list_with_prefix(prefix);
+                helper::list_with_prefix(prefix, &run_options.filter);
             }
             "all" => {
-                helper::run_all_prefix(prefix);
+                let format = run_options.format;
+                if let Some(header) = format.header()
+                    && helper::tasks_filtered_by_prefix(prefix)
+                        .any(|task| run_options.filter.selects(task))
+                {
+                    println!("{header}");
+                }
+                if !for_each_profile(&profiles, format, || {
+                    helper::run_all_prefix(prefix, &inputs_root, &run_options)
+                }) {
+                    std::process::exit(1);
+                }
+            }
+            "bench" => {
+                bench::bench_prefix(prefix, &inputs_root, &bench_options, &run_options.filter);
+            }
+            "diff" if prefix.last().is_some_and(|arg| arg == "perf") => {
+                let prefix = &prefix[..prefix.len() - 1];
+                if !perf::diff_prefix(prefix, &bench_options.history, &diff_options) {
+                    std::process::exit(1);
+                }
+            }
+            "crosscheck" => {
+                if !crosscheck::crosscheck_prefix(
+                    prefix,
+                    &inputs_root,
+                    &bench_options,
+                    &run_options.filter,
+                ) {
+                    std::process::exit(1);
+                }
+            }
+            "verify" if helper::input::input_source() != InputSource::Personal => {
+                println!(
+                    "verify checks the personal inputs and can't be used with --input or --example"
+                );
+                std::process::exit(1);
+            }
+            "verify" => {
+                if !for_each_profile(&profiles, run_options.format, || {
+                    verify::verify_prefix(prefix, &inputs_root, &run_options.filter)
+                }) {
+                    std::process::exit(1);
+                }
+            }
+            "report" => {
+                if let Err(err) = report::report_prefix(
+                    prefix,
+                    &inputs_root,
+                    &bench_options.history,
+                    write_report,
+                ) {
+                    println!("{err}");
+                    std::process::exit(1);
+                }
+            }
+            "watch" => {
+                if let Err(err) = watch::watch(prefix, &inputs_root, &run_options.filter) {
+                    println!("{err}");
+                    std::process::exit(1);
+                }
+            }
+            "serve" if prefix.is_empty() => {
+                let timeout = run_options.timeout.unwrap_or(serve::DEFAULT_TIMEOUT);
+                if let Err(err) = serve::serve(port, timeout) {
+                    println!("{err}");
+                    std::process::exit(1);
+                }
+            }
+            "fetch" => {
+                let [year, day] = prefix else {
+                    println!("Fetch takes exactly a year and day before it!");
+                    return;
+                };
+                let (Ok(year), Ok(day)) = (year.parse(), day.parse()) else {
+                    println!("{year} {day} is not a valid year and day");
+                    return;
+                };
+                if let Err(err) = client::fetch(&inputs_root, year, day) {
+                    println!("{err}");
+                    std::process::exit(1);
+                }
             }
             "generate" => {
                 if let [year, day] = prefix {
@@ -31,21 +239,36 @@ fn main() {
                         println!("{day} is not a valid day number");
                         return;
                     };
-                    generate(day, year);
+                    if let Err(err) = generate::generate(year, day, &inputs_root) {
+                        println!("{err}");
+                        std::process::exit(1);
+                    }
                 } else {
-                    println!("Generate takes exactly two argumenrs!")
+                    println!("Generate takes exactly two arguments!")
                 };
             }
             _ => {
                 let mut found = false;
-                for task in TASKS {
-                    if task.path == args {
-                        (task.run)();
+                let format = run_options.format;
+                let succeeded = for_each_profile(&profiles, format, || {
+                    let mut succeeded = true;
+                    for task in helper::tasks_matching(&args) {
+                        if !found && let Some(header) = format.header() {
+                            println!("{header}");
+                        }
+                        succeeded &= matches!(
+                            helper::run_task(task, &inputs_root, format),
+                            helper::Outcome::Succeeded(_)
+                        );
                         found = true;
                     }
-                }
+                    succeeded
+                });
                 if !found {
+                    helper::suggest_closest(&args, false);
                     help();
+                } else if !succeeded {
+                    std::process::exit(1);
                 }
             }
         }
@@ -57,56 +280,322 @@ fn main() {
 #[distributed_slice(TASKS)]
 static HELP: Task = Task {
     path: &["help"],
-    run: help,
-    include_in_all: false,
+    run: |_| {
+        help();
+        Answer::Unit
+    },
+    tags: &[Tag::Debug],
+    params: &[],
 };
 
+/// Remove `name <value>` from the arguments, returning the value if the option was present
+fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>, String> {
+    let Some(idx) = args.iter().position(|arg| arg == name) else {
+        return Ok(None);
+    };
+    if idx + 1 >= args.len() {
+        return Err(format!("{name} requires a value"));
+    }
+    let value = args.remove(idx + 1);
+    args.remove(idx);
+    Ok(Some(value))
+}
+
+fn record_guess(path: &[String], rest: &[String], inputs_root: &Path) {
+    let [year, day, part] = path else {
+        println!("Guess takes exactly a year, day and part before it!");
+        return;
+    };
+    let (Ok(year), Ok(day)) = (year.parse(), day.parse()) else {
+        println!("{year} {day} is not a valid year and day");
+        return;
+    };
+    let (answer, verdict) = match rest {
+        [answer] => (answer, None),
+        [answer, verdict] => match verdict.parse::<GuessVerdict>() {
+            Ok(verdict) => (answer, Some(verdict)),
+            Err(err) => {
+                println!("{err}");
+                return;
+            }
+        },
+        _ => {
+            println!("Guess takes an answer and an optional verdict after it!");
+            return;
+        }
+    };
+
+    let guess = Guess {
+        answer: answer.clone(),
+        verdict,
+    };
+    match helper::guesses::record_guess(inputs_root, year, day, part, &guess) {
+        Ok(()) => println!("Recorded {answer} for {year} {day} {part}"),
+        Err(err) => println!("Failed to record guess: {err}"),
+    }
+}
+
+/// Submit the given answer, or the answer of the task for the personal input,
+/// returns whether it was right
+fn submit(path: &[String], rest: &[String], inputs_root: &Path) -> bool {
+    let mut tasks = helper::tasks_matching(path);
+    let (Some(task), None, [_, _, _]) = (tasks.next(), tasks.next(), path) else {
+        println!("Submit takes exactly a year, day and part before it!");
+        helper::suggest_closest(path, false);
+        return false;
+    };
+    let (Some((year, day)), Some(part)) = (task.year_day(), task.path.last()) else {
+        println!("{} is not a puzzle part", task.path.join(" "));
+        return false;
+    };
+
+    let answer = match rest {
+        [answer] => answer.clone(),
+        [] if helper::input::input_source() != InputSource::Personal => {
+            println!(
+                "submit runs the task on the personal input and can't be used with --input or --example"
+            );
+            return false;
+        }
+        [] => {
+            let input = match task.load_input(inputs_root) {
+                Ok(input) => input,
+                Err(err) => {
+                    println!("{err}");
+                    return false;
+                }
+            };
+            match task.solve(&input) {
+                Answer::Unit => {
+                    println!("{} has no answer to submit", task.path.join(" "));
+                    return false;
+                }
+                answer => answer.to_string(),
+            }
+        }
+        _ => {
+            println!("Submit takes an optional answer after it!");
+            return false;
+        }
+    };
+
+    println!("Submitting {answer} for {}", task.path.join(" "));
+    match client::submit(inputs_root, year, day, part, &answer) {
+        Ok(correct) => correct,
+        Err(err) => {
+            println!("{err}");
+            false
+        }
+    }
+}
+
+fn take_numeric_options(
+    args: &mut Vec<String>,
+    bench_options: &mut bench::BenchOptions,
+    run_options: &mut helper::RunOptions,
+) -> Result<(), String> {
+    if let Some(warmup) = take_parsed_option(args, "--warmup")? {
+        bench_options.warmup = warmup;
+    }
+    if let Some(runs) = take_parsed_option(args, "--runs")? {
+        bench_options.runs = runs;
+    }
+    if let Some(jobs) = take_parsed_option(args, "--jobs")? {
+        run_options.jobs = jobs;
+    }
+    if let Some(timeout) = take_option(args, "--timeout")? {
+        let secs = timeout
+            .parse()
+            .map_err(|err: ParseFloatError| err.to_string());
+        let timeout = secs
+            .and_then(|secs| Duration::try_from_secs_f64(secs).map_err(|err| err.to_string()))
+            .map_err(|err| format!("{timeout} is not a valid value for --timeout: {err}"))?;
+        run_options.timeout = Some(timeout);
+    }
+    if let Some(format) = take_parsed_option(args, "--format")? {
+        run_options.format = format;
+    }
+    Ok(())
+}
+
+fn take_perf_options(
+    args: &mut Vec<String>,
+    bench_options: &mut bench::BenchOptions,
+    diff_options: &mut perf::DiffOptions,
+) -> Result<(), String> {
+    if let Some(history) = take_option(args, "--history")? {
+        bench_options.history = PathBuf::from(history);
+    }
+    if let Some(threshold) = take_parsed_option(args, "--threshold")? {
+        diff_options.threshold = threshold;
+    }
+    diff_options.baseline = take_option(args, "--baseline")?;
+    Ok(())
+}
+
+fn take_tag_filter(args: &mut Vec<String>) -> Result<TagFilter, String> {
+    let mut filter = TagFilter::default();
+    while let Some(tag) = take_parsed_option(args, "--tag")? {
+        filter.tags.push(tag);
+    }
+    while let Some(tag) = take_parsed_option(args, "--exclude-tag")? {
+        filter.exclude_tags.push(tag);
+    }
+    Ok(filter)
+}
+
+fn take_params(args: &mut Vec<String>) -> Result<BTreeMap<String, String>, String> {
+    let mut overrides = BTreeMap::new();
+    while let Some(arg) = take_option(args, "--param")? {
+        let (name, value) = helper::params::parse_override(&arg)?;
+        if !TASKS
+            .iter()
+            .any(|task| task.params.iter().any(|param| param.name == name))
+        {
+            return Err(format!("no task has a parameter named {name}"));
+        }
+        overrides.insert(name, value);
+    }
+    Ok(overrides)
+}
+
+fn take_input_source(args: &mut Vec<String>) -> Result<InputSource, String> {
+    match (
+        take_option(args, "--input")?,
+        take_option(args, "--example")?,
+    ) {
+        (Some(_), Some(_)) => Err("--input and --example can't be used together".to_owned()),
+        (Some(input), None) => Ok(InputSource::from_arg(&input)),
+        (None, Some(example)) => Ok(InputSource::Example(example)),
+        (None, None) => Ok(InputSource::Personal),
+    }
+}
+
+/// The profiles selected with `--profile <name>` or `--all-profiles`,
+/// without either the profile from the environment is used
+fn take_profiles(args: &mut Vec<String>, inputs_root: &Path) -> Result<Vec<String>, String> {
+    let all = take_flag(args, "--all-profiles");
+    match (take_option(args, "--profile")?, all) {
+        (Some(_), true) => Err("--profile and --all-profiles can't be used together".to_owned()),
+        (Some(profile), false) => {
+            helper::input::set_profile(&profile);
+            Ok(vec![profile])
+        }
+        (None, true) => Ok(helper::input::profiles(inputs_root)),
+        (None, false) => Ok(vec![helper::input::profile()]),
+    }
+}
+
+/// Run once per profile with the profile selected, returns whether all runs succeeded
+///
+/// The profile banners go to stderr for structured formats, whose records name the profile.
+fn for_each_profile(
+    profiles: &[String],
+    format: OutputFormat,
+    mut run: impl FnMut() -> bool,
+) -> bool {
+    let mut succeeded = true;
+    for profile in profiles {
+        if profiles.len() > 1 && format == OutputFormat::Text {
+            println!("Profile {profile}");
+        } else if profiles.len() > 1 {
+            eprintln!("Profile {profile}");
+        }
+        helper::input::set_profile(profile);
+        succeeded &= run();
+    }
+    succeeded
+}
+
+fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    let Some(idx) = args.iter().position(|arg| arg == name) else {
+        return false;
+    };
+    args.remove(idx);
+    true
+}
+
+fn take_parsed_option<T: FromStr<Err: Display>>(
+    args: &mut Vec<String>,
+    name: &str,
+) -> Result<Option<T>, String> {
+    take_option(args, name)?
+        .map(|arg| {
+            arg.parse()
+                .map_err(|err| format!("{arg} is not a valid value for {name}: {err}"))
+        })
+        .transpose()
+}
+
 fn help() {
     println!(
         "\
         Available commands are:\n\
         \t- help                    - to show this help\n\
+        \t- <year> <day> <part>     - to run a single task, years and days may be numbers (8, 08, day8),\n\
+        \t                            ranges (1..5, 1..=5) or lists (2022,2023)\n\
         \t- [prefix ...] list       - to list all task with that prefix\n\
-        \t- [prefix ...] all        - to run all task with that prefix (unless marked as not included)\n\
-        \t- <year> <day> generate   - to instantiate a template module for the specified year and day\n\
+        \t- [prefix ...] all        - to run all task with that prefix (unless tagged)\n\
+        \t- [prefix ...] bench      - to time all task with that prefix (unless tagged),\n\
+        \t                            read is the time to read the input, for days registering their parser\n\
+        \t                            parse is the median time to parse it and solve the median without parsing,\n\
+        \t                            min, median, mean and max are of the whole runs\n\
+        \t- [prefix ...] perf diff  - to compare the latest bench results with their baseline in the history file,\n\
+        \t                            flagging tasks that got slower than the threshold\n\
+        \t- [prefix ...] verify     - to check all task with that prefix against the known answers in inputs/answers\n\
+        \t- [prefix ...] crosscheck - to run all task with that prefix next to their variants,\n\
+        \t                            checking that they agree and comparing their timings\n\
+        \t- [prefix ...] report     - to print a markdown table per year of the implemented parts, known answers,\n\
+        \t                            slow tags and latest bench medians\n\
+        \t- <year> <day> [part] watch - to re-run the parts and example answers of a day whenever its\n\
+        \t                            input or example files change, rebuilding when its source changes\n\
+        \t- serve                   - to answer GET /tasks with the task listing and POST /solve/<year>/<day>/<part>\n\
+        \t                            with the answer for the request body as input, as JSON on localhost\n\
+        \t- completions <shell>     - to print the completion script for bash, zsh or fish, e.g. for bash\n\
+        \t                            `source <(runner completions bash)`, it completes the tasks known to the runner\n\
+        \t- <year> <day> fetch      - to download the personal input of a day into inputs/personal\n\
+        \t- <year> <day> <part> submit [answer]\n\
+        \t                          - to submit the answer, by default the one of the task for the personal input,\n\
+        \t                            recording it in inputs/answers as known answer or guess with its verdict\n\
+        \t- <year> <day> generate   - to instantiate a template module for the specified year and day,\n\
+        \t                            creating the year crate if needed and skipping files that already exist\n\
+        \t- <year> <day> <part> guess <answer> [too-high|too-low|wrong]\n\
+        \t                          - to record a submitted answer and its verdict in inputs/answers\n\
+        \n\
+        Options:\n\
+        \t--inputs <dir>            - the inputs root to load personal inputs from (default: $AOC_INPUTS or the workspace inputs)\n\
+        \t--warmup <n>              - the number of untimed runs per task for bench and crosscheck (default: 1)\n\
+        \t--runs <n>                - the number of timed runs per task for bench and crosscheck (default: 10)\n\
+        \t--history <file>          - the file bench appends its results to and perf diff reads (default: perf-history.jsonl)\n\
+        \t--baseline <commit>       - the commit perf diff compares against (default: the last other recorded commit)\n\
+        \t--threshold <percent>     - how much slower a task may get before perf diff flags it (default: 10)\n\
+        \t--write                   - to let report replace the report section of the year READMEs,\n\
+        \t                            and of the top-level README when run without a prefix\n\
+        \t--tag <tag>               - to only select tasks with that tag and run them with all, can be repeated\n\
+        \t--exclude-tag <tag>       - to not select tasks with that tag, can be repeated\n\
+        \t--cached                  - to reuse the answers of all and single tasks from target/answer-cache when neither\n\
+        \t                            the input, the parameters nor the sources of the day changed since\n\
+        \t--jobs <n>                - the number of tasks to run in parallel for all (default: 1)\n\
+        \t--timeout <secs>          - the time after which a task is reported as timed out for all and serve\n\
+        \t                            (default for serve: 60)\n\
+        \t--port <port>             - the port serve listens on (default: 8080)\n\
+        \t--format <text|json|csv>  - to print one record per task with its path, status, answer, duration and error\n\
+        \t                            instead of the task output for all and single tasks (default: text)\n\
+        \t--input <path>            - to run the selected tasks on that file instead of their personal input, - for stdin\n\
+        \t--example <n>             - to run the selected tasks on inputs/example/yearYYYY/dayDD.example<n>.txt\n\
+        \t--profile <name>          - to use the personal inputs and answers of that profile in inputs/personal/<name>\n\
+        \t                            and inputs/answers/<name> (default: $AOC_PROFILE or the inputs directly in there)\n\
+        \t--all-profiles            - to run or verify the tasks once for every profile\n\
+        \t--param <name>=<value>    - to override a puzzle parameter of the tasks declaring it, can be repeated,\n\
+        \t                            list shows the parameters and their defaults\n\
+        \n\
+        fetch and submit read the session cookie from $AOC_CONFIG or ~/.config/aoc/config.toml:\n\
+        \tsession = \"<cookie>\"\n\
+        \tbase_url = \"https://adventofcode.com\"  # optional, e.g. to test against a local server\n\
+        \trequest_interval = 5                   # optional, the minimal number of seconds between requests\n\
+        \n\
+        Built with the alloc-stats feature the peak heap, allocation count and allocated bytes\n\
+        of each task are reported by all, bench and single task runs.\n\
         "
     );
 }
-
-fn generate(day: u8, year: u16) {
-    let mut lib_file = std::fs::OpenOptions::new()
-        .append(true)
-        .open(format!("crates/year{year}/src/lib.rs"))
-        .unwrap();
-
-    let mut mod_file = std::fs::OpenOptions::new()
-        .write(true)
-        .create_new(true)
-        .open(format!("crates/year{year}/src/day{:02}.rs", day))
-        .unwrap();
-
-    let _example_input = std::fs::OpenOptions::new()
-        .write(true)
-        .create_new(true)
-        .open(format!(
-            "inputs/example/year{year}/day{:02}.example1.txt",
-            day
-        ))
-        .unwrap();
-
-    let _input = std::fs::OpenOptions::new()
-        .write(true)
-        .create_new(true)
-        .open(format!("inputs/personal/year{year}/day{:02}.txt", day))
-        .unwrap();
-
-    writeln!(lib_file, "pub mod day{:02};", day).unwrap();
-    write!(
-        mod_file,
-        "{}",
-        include_str!("../template/lib-mod.rs")
-            .replace("DD", &format!("{day:02}"))
-            .replace("YYYY", &format!("{year}"))
-    )
-    .unwrap();
-}
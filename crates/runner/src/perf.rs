@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+pub const DEFAULT_HISTORY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../perf-history.jsonl");
+
+/// One bench result in the history file, stored as one JSON object per line
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub path: String,
+    pub commit: String,
+    pub input: String,
+    pub median: Duration,
+    pub timestamp: u64,
+}
+
+impl Record {
+    fn to_json(&self) -> String {
+        serde_json::json!({
+            "path": self.path,
+            "commit": self.commit,
+            "input": self.input,
+            "median_secs": self.median.as_secs_f64(),
+            "timestamp": self.timestamp,
+        })
+        .to_string()
+    }
+
+    fn from_json(line: &str) -> Option<Self> {
+        let value = serde_json::from_str::<serde_json::Value>(line).ok()?;
+        Some(Self {
+            path: value["path"].as_str()?.to_owned(),
+            commit: value["commit"].as_str()?.to_owned(),
+            input: value["input"].as_str()?.to_owned(),
+            median: Duration::try_from_secs_f64(value["median_secs"].as_f64()?).ok()?,
+            timestamp: value["timestamp"].as_u64().unwrap_or_default(),
+        })
+    }
+}
+
+/// The checked out commit as given by `git describe`, marked `-dirty` with uncommitted changes
+pub fn current_commit() -> String {
+    std::process::Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_owned())
+        .unwrap_or_else(|| "unknown".to_owned())
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+pub fn append(history: &Path, records: &[Record]) -> std::io::Result<()> {
+    if let Some(parent) = history.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(history)?;
+    for record in records {
+        writeln!(file, "{}", record.to_json())?;
+    }
+    Ok(())
+}
+
+/// The records of the history file in the order they were recorded, skipping malformed lines
+pub fn load(history: &Path) -> std::io::Result<Vec<Record>> {
+    match std::fs::read_to_string(history) {
+        Ok(content) => Ok(content.lines().filter_map(Record::from_json).collect()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(vec![]),
+        Err(err) => Err(err),
+    }
+}
+
+pub struct DiffOptions {
+    /// the percentage a task may get slower before it is flagged
+    pub threshold: f64,
+    /// the commit to compare against, by default the last other commit recorded for the task
+    pub baseline: Option<String>,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 10.0,
+            baseline: None,
+        }
+    }
+}
+
+/// Compare the latest record of each task and input with its baseline,
+/// returns whether no task got slower than the threshold allows
+pub fn diff_prefix<S: AsRef<str>>(prefix: &[S], history: &Path, options: &DiffOptions) -> bool {
+    let records = match load(history) {
+        Ok(records) => records,
+        Err(err) => {
+            println!("failed to read {}: {err}", history.display());
+            return false;
+        }
+    };
+
+    let mut by_task = BTreeMap::<(&str, &str), Vec<&Record>>::new();
+    for record in &records {
+        by_task
+            .entry((record.path.as_str(), record.input.as_str()))
+            .or_default()
+            .push(record);
+    }
+
+    let mut compared = 0;
+    let mut regressions = vec![];
+    println!(
+        "{:<24} {:>16} {:>12} {:>16} {:>12} {:>9}",
+        "task", "baseline", "", "current", "", "change"
+    );
+    for task in helper::tasks_filtered_by_prefix(prefix) {
+        let path = task.path.join(" ");
+        for task_records in by_task
+            .iter()
+            .filter(|((record_path, _), _)| *record_path == path)
+            .map(|(_, task_records)| task_records)
+        {
+            let Some(current) = task_records.last() else {
+                continue;
+            };
+            let baseline = task_records
+                .iter()
+                .rev()
+                .find(|record| match &options.baseline {
+                    Some(baseline) => record.commit == *baseline,
+                    None => record.commit != current.commit,
+                });
+            let Some(baseline) = baseline else {
+                continue;
+            };
+            if baseline.commit == current.commit {
+                continue;
+            }
+
+            compared += 1;
+            let change =
+                (current.median.as_secs_f64() / baseline.median.as_secs_f64() - 1.0) * 100.0;
+            let slower = change > options.threshold;
+            let line = format!(
+                "{:<24} {:>16} {:>12} {:>16} {:>12} {:>8.1}% {}",
+                path,
+                baseline.commit,
+                format!("{:.2?}", baseline.median),
+                current.commit,
+                format!("{:.2?}", current.median),
+                change,
+                if slower { "SLOWER" } else { "" },
+            );
+            println!("{}", line.trim_end());
+            if slower {
+                regressions.push(path.clone());
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "{compared} compared, {} more than {}% slower",
+        regressions.len(),
+        options.threshold
+    );
+    regressions.is_empty()
+}
@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+use helper::Tag;
+use helper::answers::Answers;
+
+use crate::generate::WORKSPACE_ROOT;
+
+const PARTS: [&str; 2] = ["part1", "part2"];
+const START_MARKER: &str = "<!-- report -->";
+const END_MARKER: &str = "<!-- /report -->";
+
+#[derive(Default)]
+struct DayReport {
+    /// whether the part is implemented and whether its answer is known
+    parts: BTreeMap<&'static str, bool>,
+    slow: bool,
+    medians: BTreeMap<&'static str, Duration>,
+}
+
+#[derive(Default)]
+struct YearReport {
+    days: BTreeMap<u8, DayReport>,
+}
+
+impl YearReport {
+    fn stars(&self) -> usize {
+        self.days
+            .values()
+            .flat_map(|day| day.parts.values())
+            .filter(|known| **known)
+            .count()
+    }
+
+    fn parts(&self) -> usize {
+        self.days.values().map(|day| day.parts.len()).sum()
+    }
+
+    fn table(&self, year: u16) -> String {
+        let mut table = String::from(
+            "| Day | Solution | Part 1 | Part 2 | Slow | Part 1 median | Part 2 median |\n\
+             |----:|----------|:------:|:------:|:----:|--------------:|--------------:|\n",
+        );
+        for (day, report) in &self.days {
+            let part = |part| match report.parts.get(part) {
+                Some(true) => "⭐",
+                Some(false) => "✔",
+                None => "",
+            };
+            let median = |part| {
+                report
+                    .medians
+                    .get(part)
+                    .map_or_else(String::new, |median| format!("{median:.2?}"))
+            };
+            table.push_str(&format!(
+                "| [{day}](https://adventofcode.com/{year}/day/{day}) | [day{day:02}.rs](src/day{day:02}.rs) | {} | {} | {} | {} | {} |\n",
+                part(PARTS[0]),
+                part(PARTS[1]),
+                if report.slow { "🐌" } else { "" },
+                median(PARTS[0]),
+                median(PARTS[1]),
+            ));
+        }
+        table.push_str(
+            "\n⭐ answer known, ✔ implemented without a known answer, `runner verify` checks the known answers, \
+             medians are the latest recorded bench results\n",
+        );
+        table
+    }
+}
+
+fn summary(years: &BTreeMap<u16, YearReport>) -> String {
+    let mut table = String::from(
+        "| Year | Days | Parts | Answers known |\n\
+         |------|-----:|------:|--------------:|\n",
+    );
+    for (year, report) in years {
+        table.push_str(&format!(
+            "| [{year}](crates/year{year}/README.md) | {} | {} | {} |\n",
+            report.days.len(),
+            report.parts(),
+            report.stars(),
+        ));
+    }
+    table
+}
+
+/// Replace the section between the report markers, appending it if the markers are missing
+fn replace_section(content: &str, section: &str) -> String {
+    let section = format!("{START_MARKER}\n{section}{END_MARKER}\n");
+    if let Some(start) = content.find(START_MARKER)
+        && let Some(end) = content[start..].find(END_MARKER)
+    {
+        let end = start + end + END_MARKER.len();
+        let rest = content[end..].strip_prefix('\n').unwrap_or(&content[end..]);
+        format!("{}{section}{rest}", &content[..start])
+    } else {
+        let separator = if content.ends_with('\n') {
+            "\n"
+        } else {
+            "\n\n"
+        };
+        format!("{content}{separator}{section}")
+    }
+}
+
+/// Write the section into a file given relative to the workspace root
+fn write_section(relative: &str, section: &str) -> Result<(), String> {
+    let path = Path::new(WORKSPACE_ROOT).join(relative);
+    let content = std::fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {relative}: {err}"))?;
+    let updated = replace_section(&content, section);
+    if updated == content {
+        println!("{relative} is up to date");
+        return Ok(());
+    }
+    std::fs::write(&path, updated).map_err(|err| format!("failed to write {relative}: {err}"))?;
+    println!("Updated {relative}");
+    Ok(())
+}
+
+/// Print a markdown table per year of the implemented parts, their known answers, slow tags
+/// and latest bench medians on the personal inputs, with `write` the tables replace the report section of the READMEs
+pub fn report_prefix<S: AsRef<str>>(
+    prefix: &[S],
+    inputs_root: &Path,
+    history: &Path,
+    write: bool,
+) -> Result<(), String> {
+    let records = crate::perf::load(history)
+        .map_err(|err| format!("failed to read {}: {err}", history.display()))?;
+    // the latest median per path and input, later records replace earlier ones
+    let medians = records
+        .into_iter()
+        .map(|record| ((record.path, record.input), record.median))
+        .collect::<BTreeMap<_, _>>();
+    let mut input_hashes = BTreeMap::new();
+
+    let mut answers = BTreeMap::new();
+    let mut years = BTreeMap::<u16, YearReport>::new();
+    for task in helper::tasks_filtered_by_prefix(prefix) {
+        let Some((year, day)) = task.year_day() else {
+            continue;
+        };
+        let Some(part) = PARTS
+            .into_iter()
+            .find(|part| task.path.last() == Some(part))
+        else {
+            continue;
+        };
+        let answers = answers.entry(year).or_insert_with(|| {
+            Answers::load(inputs_root, year).unwrap_or_else(|err| {
+                println!("{err}");
+                Answers::default()
+            })
+        });
+
+        let report = years.entry(year).or_default().days.entry(day).or_default();
+        report
+            .parts
+            .insert(part, answers.expected_for(task).is_some());
+        report.slow |= task.tags.contains(&Tag::Slow);
+        // only benches of the personal input of the selected profile are reported
+        let input_hash = input_hashes.entry((year, day)).or_insert_with(|| {
+            helper::input::load_personal_input(inputs_root, year, day)
+                .ok()
+                .map(|input| helper::cache::input_hash(&input))
+        });
+        if let Some(input_hash) = input_hash
+            && let Some(median) = medians.get(&(task.path.join(" "), input_hash.clone()))
+        {
+            report.medians.insert(part, *median);
+        }
+    }
+
+    if years.is_empty() {
+        helper::suggest_closest(prefix, true);
+        return Err("no parts match the prefix".to_owned());
+    }
+
+    for (year, report) in &years {
+        if write {
+            write_section(
+                &format!("crates/year{year}/README.md"),
+                &report.table(*year),
+            )?;
+        } else {
+            println!("## {year}");
+            println!();
+            println!("{}", report.table(*year));
+        }
+    }
+
+    // the summary only covers the selected years, so the top-level README is only written for all
+    if write && prefix.is_empty() {
+        write_section("README.md", &summary(&years))?;
+    } else if !write {
+        println!("## Summary");
+        println!();
+        print!("{}", summary(&years));
+    }
+    Ok(())
+}
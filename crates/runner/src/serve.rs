@@ -0,0 +1,195 @@
+use std::fmt::Display;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+use helper::{Answer, Outcome};
+
+pub const DEFAULT_PORT: u16 = 8080;
+/// the time a task may run when no `--timeout` is given
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+/// the time a client may take to send its request
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_BODY: usize = 16 * 1024 * 1024;
+
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: String) -> Self {
+        Self { status, body }
+    }
+
+    fn error(status: u16, error: impl Display) -> Self {
+        Self::json(
+            status,
+            serde_json::json!({ "error": error.to_string() }).to_string(),
+        )
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            _ => "Internal Server Error",
+        }
+    }
+
+    fn write_to(&self, stream: &mut TcpStream) -> std::io::Result<()> {
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            self.reason(),
+            self.body.len(),
+            self.body
+        )?;
+        stream.flush()
+    }
+}
+
+fn read_request(stream: &TcpStream) -> Result<Request, Response> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    let bad_request = |err: std::io::Error| Response::error(400, err);
+    reader.read_line(&mut line).map_err(bad_request)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(Response::error(400, "malformed request line"));
+    };
+    let (method, path) = (method.to_owned(), path.to_owned());
+
+    let mut content_length = 0;
+    loop {
+        line.clear();
+        reader.read_line(&mut line).map_err(bad_request)?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            content_length = value
+                .trim()
+                .parse()
+                .map_err(|_| Response::error(400, "invalid Content-Length"))?;
+        }
+    }
+    if content_length > MAX_BODY {
+        return Err(Response::error(
+            413,
+            format!("the body may be at most {MAX_BODY} bytes"),
+        ));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(bad_request)?;
+    Ok(Request { method, path, body })
+}
+
+fn tasks() -> Response {
+    let tasks = helper::tasks_filtered_by_prefix::<&str>(&[])
+        .map(|task| {
+            serde_json::json!({
+                "path": task.path.join(" "),
+                "tags": task.tags.iter().map(|tag| tag.name()).collect::<Vec<_>>(),
+                "params": task.params.iter().map(|param| serde_json::json!({
+                    "name": param.name,
+                    "default": param.default,
+                })).collect::<Vec<_>>(),
+                "variants": task.variants().map(|variant| variant.name).collect::<Vec<_>>(),
+            })
+        })
+        .collect::<Vec<_>>();
+    Response::json(200, serde_json::Value::from(tasks).to_string())
+}
+
+fn solve(year: &str, day: &str, part: &str, body: Vec<u8>, timeout: Duration) -> Response {
+    let part = part.strip_prefix("part").unwrap_or(part);
+    let (Ok(year), Ok(day), Ok(part)) = (year.parse(), day.parse(), part.parse()) else {
+        return Response::error(400, format!("{year} {day} {part} is not a valid part"));
+    };
+    let Some(task) = helper::find_task(year, day, part) else {
+        return Response::error(404, helper::SolveError::UnknownPart { year, day, part });
+    };
+    let Ok(input) = String::from_utf8(body) else {
+        return Response::error(400, "the input is not valid UTF-8");
+    };
+
+    let start = Instant::now();
+    let mut outcome = Outcome::TimedOut(timeout);
+    let job: helper::parallel::Job<_> =
+        Box::new(move || match helper::solve(year, day, part, &input) {
+            Ok(answer) => Outcome::Succeeded(answer),
+            Err(helper::SolveError::Panicked(msg)) => Outcome::Panicked(msg),
+            Err(err) => Outcome::Failed(err.to_string()),
+        });
+    helper::parallel::run_ordered(vec![job], 1, Some(timeout), |_, result| {
+        if let Some(result) = result {
+            outcome = result;
+        }
+    });
+    // not an output record, the answer is of the posted input rather than of a profile's input
+    let record = serde_json::json!({
+        "path": task.path.join(" "),
+        "status": outcome.status(),
+        "answer": outcome.answer().map(Answer::to_string),
+        "duration_secs": start.elapsed().min(timeout).as_secs_f64(),
+        "error": outcome.error(),
+    });
+    Response::json(200, record.to_string())
+}
+
+fn handle(mut stream: TcpStream, timeout: Duration) {
+    _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+    let response = match read_request(&stream) {
+        Err(response) => response,
+        Ok(request) => {
+            let segments = request
+                .path
+                .trim_matches('/')
+                .split('/')
+                .collect::<Vec<_>>();
+            match (request.method.as_str(), segments.as_slice()) {
+                ("GET", ["tasks"]) => tasks(),
+                ("POST", ["solve", year, day, part]) => {
+                    solve(year, day, part, request.body, timeout)
+                }
+                (_, ["tasks"] | ["solve", _, _, _]) => {
+                    Response::error(405, format!("{} is not allowed here", request.method))
+                }
+                _ => Response::error(404, format!("no route {}", request.path)),
+            }
+        }
+    };
+    _ = response.write_to(&mut stream);
+}
+
+/// Serve `GET /tasks` and `POST /solve/{year}/{day}/{part}` on localhost until the process is stopped,
+/// tasks running longer than the timeout are reported as timed out and left running detached
+pub fn serve(port: u16, timeout: Duration) -> Result<(), String> {
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .map_err(|err| format!("failed to listen on port {port}: {err}"))?;
+    println!("Listening on http://127.0.0.1:{port}, press Ctrl+C to stop");
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                std::thread::spawn(move || handle(stream, timeout));
+            }
+            Err(err) => println!("Failed to accept a connection: {err}"),
+        }
+    }
+    Ok(())
+}
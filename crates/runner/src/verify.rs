@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use helper::TagFilter;
+use helper::answers::{Answers, Verdict};
+
+const PARTS: [&str; 2] = ["part1", "part2"];
+
+enum Status {
+    Pass,
+    Fail,
+    Unknown,
+    Skipped,
+    NoInput,
+}
+
+impl Status {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Status::Pass => "ok",
+            Status::Fail => "FAIL",
+            Status::Unknown => "?",
+            Status::Skipped => "skip",
+            Status::NoInput => "-",
+        }
+    }
+}
+
+/// Run all tasks with a known answer and print a pass/fail/unknown matrix,
+/// returns whether no task failed
+pub fn verify_prefix<S: AsRef<str>>(prefix: &[S], inputs_root: &Path, filter: &TagFilter) -> bool {
+    let mut answers = BTreeMap::new();
+    let mut matrix = BTreeMap::<(u16, u8), BTreeMap<&str, Status>>::new();
+    let mut failures = vec![];
+
+    for task in helper::tasks_filtered_by_prefix(prefix).filter(|task| filter.selects(task)) {
+        let Some((year, day)) = task.year_day() else {
+            continue;
+        };
+        let part = task.path[task.path.len() - 1];
+        if !PARTS.contains(&part) {
+            continue;
+        }
+
+        let answers = answers.entry(year).or_insert_with(|| {
+            Answers::load(inputs_root, year).unwrap_or_else(|err| {
+                println!("{err}");
+                Answers::default()
+            })
+        });
+
+        let status = match answers.expected_for(task) {
+            None => Status::Unknown,
+            Some(_) if !filter.runs(task) && task.path.len() != prefix.len() => Status::Skipped,
+            Some(expected) => match task.load_input(inputs_root) {
+                Err(_) => Status::NoInput,
+                Ok(input) => {
+                    println!("Verifying {}", task.path.join(" "));
+                    let verdict = match task.try_solve(&input) {
+                        Ok(answer) => helper::answers::check(Some(expected), &answer),
+                        Err(msg) => Verdict::Fail {
+                            expected: expected.to_owned(),
+                            actual: format!("a panic: {msg}"),
+                        },
+                    };
+                    match verdict {
+                        Verdict::Pass => Status::Pass,
+                        Verdict::Unknown => Status::Unknown,
+                        Verdict::Fail { expected, actual } => {
+                            failures.push(format!(
+                                "{}: expected {expected}, got {actual}",
+                                task.path.join(" ")
+                            ));
+                            Status::Fail
+                        }
+                    }
+                }
+            },
+        };
+
+        matrix.entry((year, day)).or_default().insert(part, status);
+    }
+
+    println!();
+    println!("{:<4} {:>3} {:>6} {:>6}", "year", "day", PARTS[0], PARTS[1]);
+    for ((year, day), parts) in &matrix {
+        print!("{year:<4} {day:>3}");
+        for part in PARTS {
+            print!(" {:>6}", parts.get(part).map_or("", Status::symbol));
+        }
+        println!();
+    }
+
+    let count = |expected: fn(&Status) -> bool| {
+        matrix
+            .values()
+            .flat_map(|parts| parts.values())
+            .filter(|status| expected(status))
+            .count()
+    };
+    println!();
+    println!(
+        "{} passed, {} failed, {} unknown, {} skipped, {} without input",
+        count(|status| matches!(status, Status::Pass)),
+        count(|status| matches!(status, Status::Fail)),
+        count(|status| matches!(status, Status::Unknown)),
+        count(|status| matches!(status, Status::Skipped)),
+        count(|status| matches!(status, Status::NoInput)),
+    );
+
+    for failure in &failures {
+        println!("{failure}");
+    }
+
+    matrix
+        .values()
+        .flat_map(|parts| parts.values())
+        .all(|status| !matches!(status, Status::Fail))
+}
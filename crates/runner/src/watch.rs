@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use helper::examples::{Example, load_examples};
+use helper::{Answer, TagFilter, Task};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// the answers before a restart, so the answers after a rebuild are still compared with them
+const PREVIOUS_ANSWERS_ENV: &str = "AOC_WATCH_PREVIOUS_ANSWERS";
+
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+fn source_path(year: u16, day: u8) -> String {
+    format!("crates/year{year}/src/day{day:02}.rs")
+}
+
+fn watched_inputs(inputs_root: &Path, year: u16, day: u8) -> Vec<PathBuf> {
+    let mut paths = vec![helper::input::personal_input_path(inputs_root, year, day)];
+    if let helper::input::InputSource::File(path) = helper::input::input_source() {
+        paths.push(path);
+    }
+    let examples = helper::examples::examples_dir(inputs_root, year);
+    let prefix = format!("day{day:02}.");
+    for entry in std::fs::read_dir(examples).into_iter().flatten().flatten() {
+        if entry.file_name().to_string_lossy().starts_with(&prefix) {
+            paths.push(entry.path());
+        }
+    }
+    paths
+}
+
+fn snapshot(paths: impl IntoIterator<Item = PathBuf>) -> Snapshot {
+    paths
+        .into_iter()
+        .map(|path| {
+            let modified = std::fs::metadata(&path)
+                .and_then(|metadata| metadata.modified())
+                .ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// A compact diff of two answers, multi-line answers only show the lines that changed
+fn answer_diff(previous: &str, current: &str) -> String {
+    if previous == current {
+        return format!("{current} (unchanged)");
+    }
+    if !previous.contains('\n') && !current.contains('\n') {
+        return format!("{previous} -> {current}");
+    }
+    let previous = previous.lines().collect::<Vec<_>>();
+    let current = current.lines().collect::<Vec<_>>();
+    let mut diff = String::from("changed");
+    for idx in 0..previous.len().max(current.len()) {
+        match (previous.get(idx), current.get(idx)) {
+            (Some(old), Some(new)) if old == new => {}
+            (old, new) => {
+                if let Some(old) = old {
+                    diff.push_str(&format!("\n\t- {old}"));
+                }
+                if let Some(new) = new {
+                    diff.push_str(&format!("\n\t+ {new}"));
+                }
+            }
+        }
+    }
+    diff
+}
+
+fn run_tasks(tasks: &[&'static Task], inputs_root: &Path, previous: &mut BTreeMap<String, String>) {
+    for task in tasks {
+        let path = task.path.join(" ");
+        let input = match task.load_input(inputs_root) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("Failed {path}: {err}");
+                continue;
+            }
+        };
+        match std::panic::catch_unwind(|| task.solve(&input)) {
+            Ok(Answer::Unit) => println!("{path}: done"),
+            Ok(answer) => {
+                let answer = answer.to_string();
+                match previous.insert(path.clone(), answer.clone()) {
+                    Some(previous) => println!("{path}: {}", answer_diff(&previous, &answer)),
+                    None if answer.contains('\n') => println!("{path}:\n{answer}"),
+                    None => println!("{path}: {answer}"),
+                }
+            }
+            Err(payload) => println!(
+                "Panicked {path}: {}",
+                helper::panic_message(payload.as_ref())
+            ),
+        }
+    }
+}
+
+fn run_examples(examples: &[Example], parts: &[&str]) {
+    for example in examples {
+        for (part, expected) in &example.answers {
+            if !parts.contains(&part.as_str()) {
+                continue;
+            }
+            match helper::examples::check_example(example, part, expected) {
+                Ok(()) => println!("example {} {part}: ok", example.name),
+                Err(err) => println!("example {} {part}: FAIL {err}", example.name),
+            }
+        }
+    }
+}
+
+/// Rebuild the runner and replace this process with the new binary, returns if that failed
+fn rebuild_and_restart(exe: &Path, answers: &BTreeMap<String, String>) {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut cargo = std::process::Command::new(cargo);
+    cargo
+        .args(["build", "-p", "runner"])
+        .current_dir(crate::generate::WORKSPACE_ROOT);
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    if cfg!(feature = "alloc-stats") {
+        cargo.args(["--features", "alloc-stats"]);
+    }
+    match cargo.status() {
+        Ok(status) if status.success() => {}
+        Ok(_) => {
+            println!("Build failed, waiting for the next change");
+            return;
+        }
+        Err(err) => {
+            println!("Failed to run cargo: {err}");
+            return;
+        }
+    }
+
+    let mut runner = std::process::Command::new(exe);
+    runner.args(std::env::args_os().skip(1)).env(
+        PREVIOUS_ANSWERS_ENV,
+        serde_json::to_string(answers).unwrap_or_default(),
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let err = runner.exec();
+        println!("Failed to restart the runner: {err}");
+    }
+    #[cfg(not(unix))]
+    match runner.status() {
+        Ok(status) => std::process::exit(status.code().unwrap_or(1)),
+        Err(err) => println!("Failed to restart the runner: {err}"),
+    }
+}
+
+/// Re-run the selected tasks of a day and its example answers whenever the input or example
+/// files change, a change of the day's source rebuilds and restarts the runner
+pub fn watch<S: AsRef<str>>(
+    prefix: &[S],
+    inputs_root: &Path,
+    filter: &TagFilter,
+) -> Result<(), String> {
+    let tasks = helper::tasks_filtered_by_prefix(prefix).collect::<Vec<_>>();
+    let mut days = tasks.iter().filter_map(|task| task.year_day());
+    let Some((year, day)) = days.next() else {
+        helper::suggest_closest(prefix, true);
+        return Err("watch needs a year and day with tasks".to_owned());
+    };
+    if days.any(|other| other != (year, day)) {
+        return Err("watch only supports the tasks of a single day".to_owned());
+    }
+    // tagged tasks are only watched when their tags or the task are selected explicitly
+    let tasks = tasks
+        .into_iter()
+        .filter(|task| {
+            filter.selects(task) && (filter.runs(task) || task.path.len() == prefix.len())
+        })
+        .collect::<Vec<_>>();
+    let parts = tasks
+        .iter()
+        .filter_map(|task| task.path.last().copied())
+        .collect::<Vec<_>>();
+
+    // resolved before the rebuild replaces the executable, after that it resolves to the deleted file
+    let exe = std::env::current_exe().map_err(|err| format!("failed to find the runner: {err}"))?;
+    let source = Path::new(crate::generate::WORKSPACE_ROOT).join(source_path(year, day));
+    let mut previous_source = snapshot([source.clone()]);
+    let mut previous_inputs = Snapshot::new();
+    let mut answers = std::env::var(PREVIOUS_ANSWERS_ENV)
+        .ok()
+        .and_then(|answers| serde_json::from_str::<BTreeMap<String, String>>(&answers).ok())
+        .unwrap_or_default();
+
+    println!(
+        "Watching {} and the inputs of {year} day {day}, press Ctrl+C to stop",
+        source_path(year, day)
+    );
+    loop {
+        let inputs = snapshot(watched_inputs(inputs_root, year, day));
+        if inputs != previous_inputs {
+            println!();
+            run_tasks(&tasks, inputs_root, &mut answers);
+            match load_examples(inputs_root, year) {
+                Ok(mut examples) => {
+                    examples.retain(|example| example.day == day);
+                    run_examples(&examples, &parts);
+                }
+                Err(err) => println!("{err}"),
+            }
+            previous_inputs = inputs;
+        }
+
+        std::thread::sleep(POLL_INTERVAL);
+
+        let source_snapshot = snapshot([source.clone()]);
+        if source_snapshot != previous_source {
+            println!();
+            println!("{} changed, rebuilding", source_path(year, day));
+            rebuild_and_restart(&exe, &answers);
+            previous_source = source_snapshot;
+        }
+    }
+}
@@ -1,29 +1,26 @@
-use helper::{Task, TASKS};
+use helper::{PARSERS, Parser, TASKS, Tag, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/yearYYYY/dayDD.txt"
-));
-
-#[cfg(test)]
-const INPUT_EXAMPLE1: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/example/yearYYYY/dayDD.example1.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["YYYY", "DD", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[Tag::Unfinished],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["YYYY", "DD", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[Tag::Unfinished],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["YYYY", "DD"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 fn parse_input(input: &str) -> impl Iterator<Item = u32> + '_ {
@@ -40,23 +37,3 @@ pub fn part2(input: &str) -> u32 {
     let mut iter = parse_input(input);
     todo!("part2 WIP")
 }
-
-#[test]
-fn part1_example1() {
-    assert_eq!(part1(INPUT_EXAMPLE1), 7);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 1292);
-}
-
-#[test]
-fn part2_example1() {
-    assert_eq!(part2(INPUT_EXAMPLE1), 5);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1262);
-}
@@ -0,0 +1,5 @@
+use aocYYYY as _;
+
+fn main() {
+    helper::examples::test_examples(YYYY);
+}
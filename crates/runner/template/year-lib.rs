@@ -0,0 +1,6 @@
+#[test]
+fn known_answers() {
+    helper::answers::assert_year_answers(YYYY, |_| true);
+}
+
+pub mod dayDD;
@@ -1,23 +1,26 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2015/day02.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2015", "2", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2015", "2", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2015", "2"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 struct Dimensions(usize, usize, usize);
@@ -53,49 +56,3 @@ pub fn part2(input: &str) -> usize {
         })
         .sum()
 }
-
-#[test]
-fn part1_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2015/day02.example1.txt"
-    ));
-    assert_eq!(part1(input), 2 * 6 + 2 * 12 + 2 * 8 + 6);
-}
-
-#[test]
-fn part1_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2015/day02.example2.txt"
-    ));
-    assert_eq!(part1(input), 2 * 1 + 2 * 10 + 2 * 10 + 1);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 1588178);
-}
-
-#[test]
-fn part2_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2015/day02.example1.txt"
-    ));
-    assert_eq!(part2(input), 2 + 2 + 3 + 3 + 2 * 3 * 4);
-}
-
-#[test]
-fn part2_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2015/day02.example2.txt"
-    ));
-    assert_eq!(part2(input), 1 + 1 + 1 + 1 + 1 * 1 * 10);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 3783758);
-}
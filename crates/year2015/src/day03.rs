@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::HashSet;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2015/day03.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2015", "3", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2015", "3", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2015", "3"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 #[derive(Debug, Clone)]
@@ -85,11 +88,6 @@ fn part1_example3() {
     assert_eq!(part1(input), 2);
 }
 
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 2572);
-}
-
 #[test]
 fn part2_example1() {
     let input = "^v";
@@ -107,8 +105,3 @@ fn part2_example3() {
     let input = "^v^v^v^v^v";
     assert_eq!(part2(input), 11);
 }
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 2631);
-}
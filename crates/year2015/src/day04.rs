@@ -1,26 +1,23 @@
 use std::collections::VecDeque;
 
-use helper::{TASKS, Task};
+use helper::{TASKS, Tag, Task};
 use linkme::distributed_slice;
 use md5::digest::{Digest, array::Array, consts::U16};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2015/day04.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2015", "4", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2015", "4", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: false,
+    run: |input| part2(input).into(),
+    tags: &[Tag::Slow],
+    params: &[],
 };
 
 #[inline(always)]
@@ -87,13 +84,3 @@ fn part1_example2() {
     let input = "pqrstuv";
     assert_eq!(part1(input), 1048970);
 }
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 282749);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 9962624);
-}
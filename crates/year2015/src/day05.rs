@@ -1,23 +1,26 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2015/day05.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2015", "5", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2015", "5", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2015", "5"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 fn parse_input(input: &str) -> impl Iterator<Item = &str> + '_ {
@@ -92,11 +95,6 @@ fn part1_example5() {
     assert!(!p1_is_nice(input));
 }
 
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 258);
-}
-
 #[test]
 fn part2_example1() {
     let input = "qjhvhtzxzqqjkmpb";
@@ -120,8 +118,3 @@ fn part2_example4() {
     let input = "ieodomkazucvgmuy";
     assert!(!p2_is_nice(input));
 }
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 53);
-}
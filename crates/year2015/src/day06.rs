@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::ops::Range;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2015/day06.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2015", "6", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2015", "6", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2015", "6"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 #[derive(Debug, Clone)]
@@ -228,13 +231,3 @@ fn part1_example3() {
     let input = "turn off 499,499 through 500,500";
     assert_eq!(part1(input), 0);
 }
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 400410);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 15343601);
-}
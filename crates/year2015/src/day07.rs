@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::HashMap;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2015/day07.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2015", "7", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2015", "7", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2015", "7"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 enum RefOrConst<'a> {
@@ -186,13 +189,3 @@ fn part1_example() {
     assert_eq!(state.eval("x"), 123);
     assert_eq!(state.eval("y"), 456);
 }
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 46065);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 14134);
-}
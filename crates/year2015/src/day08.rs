@@ -1,23 +1,26 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2015/day08.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2015", "8", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2015", "8", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2015", "8"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 fn parse_input(input: &str) -> impl Iterator<Item = &'_ str> + '_ {
@@ -107,13 +110,3 @@ fn part1_example3() {
 fn part1_example4() {
     test(r#""\x27""#, 6, 1);
 }
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 1333);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 2046);
-}
@@ -1,27 +1,30 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::{
     cmp::Ordering,
     collections::{BTreeSet, HashMap},
 };
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2015/day09.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2015", "9", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2015", "9", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2015", "9"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 fn parse_input(input: &str) -> HashMap<(&'_ str, &'_ str), usize> {
@@ -106,31 +109,3 @@ pub fn part2(input: &str) -> usize {
         .max()
         .unwrap()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2015/day09.example.txt"
-    ));
-    assert_eq!(part1(input), 605);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 117);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2015/day09.example.txt"
-    ));
-    assert_eq!(part2(input), 982);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 909);
-}
@@ -1,23 +1,26 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2015/day10.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2015", "10", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2015", "10", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2015", "10"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 fn parse_input(input: &str) -> impl Iterator<Item = u8> + '_ {
@@ -101,13 +104,3 @@ fn part1_example5() {
     let res = look_and_say(parse_input("111221")).collect::<Vec<_>>();
     assert_eq!(res, vec![3, 1, 2, 2, 1, 1]);
 }
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 360154);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 5103798);
-}
@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::{fmt::Display, str::FromStr};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2015/day11.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2015", "11", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2015", "11", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2015", "11"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -204,13 +207,3 @@ fn part1_example1() {
 fn part1_example2() {
     assert_eq!(part1("ghijklmn"), "ghjaabcc");
 }
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), "cqjxxyzz");
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), "cqkaabcc");
-}
@@ -1,3 +1,8 @@
+#[test]
+fn known_answers() {
+    helper::answers::assert_year_answers(2015, |_| true);
+}
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::VecDeque;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day01.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "1", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "1", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "1"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 fn parse_input(input: &str) -> impl Iterator<Item = u32> + '_ {
@@ -53,31 +56,3 @@ pub fn part1(input: &str) -> u32 {
 pub fn part2(input: &str) -> u32 {
     both(input, 3)
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day01.example.txt"
-    ));
-    assert_eq!(part1(input), 7);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 1292);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day01.example.txt"
-    ));
-    assert_eq!(part2(input), 5);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1262);
-}
@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::str::FromStr;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day02.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "2", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "2", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "2"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 enum Direction {
@@ -71,31 +74,3 @@ pub fn part2(input: &str) -> i32 {
 
     depth * distance
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day02.example.txt"
-    ));
-    assert_eq!(part1(input), 10 * 15);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 1882980);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day02.example.txt"
-    ));
-    assert_eq!(part2(input), 15 * 60);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1971232560);
-}
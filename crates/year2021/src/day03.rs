@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::cmp::Ordering;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day03.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "3", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "3", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "3"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 fn parse_input(input: &str) -> impl Iterator<Item = &[u8]> + '_ {
@@ -111,31 +114,3 @@ pub fn part2(input: &str) -> u32 {
 
     oxygen_generator * co2_scrubber
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day03.example.txt"
-    ));
-    assert_eq!(part1(input), 22 * 9);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 3242606);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day03.example.txt"
-    ));
-    assert_eq!(part2(input), 23 * 10);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 4856080);
-}
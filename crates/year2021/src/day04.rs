@@ -1,24 +1,32 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day04.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "4", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "4", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "4"],
+    run: |input| {
+        let (numbers, boards) = parse_input(input);
+        helper::consume(numbers);
+        boards.for_each(helper::consume);
+    },
 };
+
 struct BoardCollector<I>(I);
 
 impl<'a, I> Iterator for BoardCollector<I>
@@ -122,31 +130,3 @@ pub fn part1(input: &str) -> u32 {
 pub fn part2(input: &str) -> u32 {
     both(input, DesiredResult::Loose)
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day04.example.txt"
-    ));
-    assert_eq!(part1(input), 188 * 24);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 6592);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day04.example.txt"
-    ));
-    assert_eq!(part2(input), 148 * 13);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 31755);
-}
@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::HashMap;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day05.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "5", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "5", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "5"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 struct Line {
@@ -98,31 +101,3 @@ pub fn part1(input: &str) -> usize {
 pub fn part2(input: &str) -> usize {
     both(input, false)
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day05.example.txt"
-    ));
-    assert_eq!(part1(input), 5);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 5084);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day05.example.txt"
-    ));
-    assert_eq!(part2(input), 12);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 17882);
-}
@@ -1,24 +1,33 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Param, Parser, TASKS, Task, param};
 use linkme::distributed_slice;
 use std::collections::VecDeque;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day06.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "6", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| both(input, param("days")).into(),
+    tags: &[],
+    params: &[Param {
+        name: "days",
+        default: "80",
+    }],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "6", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| both(input, param("days")).into(),
+    tags: &[],
+    params: &[Param {
+        name: "days",
+        default: "256",
+    }],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "6"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 fn parse_input(input: &str) -> impl Iterator<Item = usize> + '_ {
@@ -44,39 +53,3 @@ pub fn both(input: &str, days: u32) -> usize {
     }
     counter
 }
-
-pub fn part1(input: &str) -> usize {
-    both(input, 80)
-}
-
-pub fn part2(input: &str) -> usize {
-    both(input, 256)
-}
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day06.example.txt"
-    ));
-    assert_eq!(part1(input), 5934);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 349549);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day06.example.txt"
-    ));
-    assert_eq!(part2(input), 26984457539);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1589590444365);
-}
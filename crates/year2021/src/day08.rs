@@ -1,23 +1,26 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day08.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "8", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "8", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "8"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 type Segments = u8;
@@ -135,49 +138,3 @@ pub fn part1(input: &str) -> usize {
 pub fn part2(input: &str) -> usize {
     parse_input(input).map(|sequence| sequence.decode()).sum()
 }
-
-#[test]
-fn part1_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day08.example1.txt"
-    ));
-    assert_eq!(part1(input), 0);
-}
-
-#[test]
-fn part1_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day08.example2.txt"
-    ));
-    assert_eq!(part1(input), 26);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 397);
-}
-
-#[test]
-fn part2_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day08.example1.txt"
-    ));
-    assert_eq!(part2(input), 5353);
-}
-
-#[test]
-fn part2_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day08.example2.txt"
-    ));
-    assert_eq!(part2(input), 61229);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1027422);
-}
@@ -1,23 +1,26 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day09.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "9", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "9", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "9"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 use std::collections::{HashSet, VecDeque};
@@ -94,31 +97,3 @@ pub fn part2(input: &str) -> usize {
     basins.reverse();
     basins.iter().take(3).product()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day09.example.txt"
-    ));
-    assert_eq!(part1(input), 2 + 1 + 6 + 6);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 480);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day09.example.txt"
-    ));
-    assert_eq!(part2(input), 9 * 14 * 9);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1045660);
-}
@@ -1,25 +1,28 @@
 use crate::day10::Delimiter::{AngleBracket, Brace, Bracket, Parenthesis};
 use crate::day10::Side::{Close, Open};
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day10.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "10", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "10", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "10"],
+    run: |input| parse_input(input).flatten().for_each(helper::consume),
 };
 
 #[derive(Debug)]
@@ -115,31 +118,3 @@ pub fn part2(input: &str) -> usize {
     completions.sort_unstable();
     completions[completions.len() / 2]
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day10.example.txt"
-    ));
-    assert_eq!(part1(input), 2 * 3 + 57 + 1197 + 25137);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 318081);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day10.example.txt"
-    ));
-    assert_eq!(part2(input), 288957);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 4361305341);
-}
@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::HashSet;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day11.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "11", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "11", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "11"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 fn parse_input(input: &str) -> [[u8; 10]; 10] {
@@ -105,31 +108,3 @@ pub fn part2(input: &str) -> u32 {
         }
     }
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day11.example.txt"
-    ));
-    assert_eq!(part1(input), 1656);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 1640);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day11.example.txt"
-    ));
-    assert_eq!(part2(input), 195);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 312);
-}
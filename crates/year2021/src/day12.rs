@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::HashMap;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day12.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "12", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "12", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "12"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 pub struct Graph<'a> {
@@ -111,67 +114,3 @@ pub fn part2(input: &str) -> u32 {
     let graph = parse_input(input);
     at_most_one_duplicate(&graph, &mut vec!["start"])
 }
-
-#[test]
-fn part1_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day12.example1.txt"
-    ));
-    assert_eq!(part1(input), 10);
-}
-
-#[test]
-fn part1_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day12.example2.txt"
-    ));
-    assert_eq!(part1(input), 19);
-}
-
-#[test]
-fn part1_example3() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day12.example3.txt"
-    ));
-    assert_eq!(part1(input), 226);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 4720);
-}
-
-#[test]
-fn part2_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day12.example1.txt"
-    ));
-    assert_eq!(part2(input), 36);
-}
-
-#[test]
-fn part2_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day12.example2.txt"
-    ));
-    assert_eq!(part2(input), 103);
-}
-
-#[test]
-fn part2_example3() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day12.example3.txt"
-    ));
-    assert_eq!(part2(input), 3509);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 147848);
-}
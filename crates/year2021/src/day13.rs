@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{Answer, PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::HashSet;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day13.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "13", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "13", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| Answer::Art(part2(input)),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "13"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Hash, Copy, Clone, Debug)]
@@ -118,20 +121,6 @@ pub fn part2(input: &str) -> String {
     result
 }
 
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day13.example.txt"
-    ));
-    assert_eq!(part1(input), 17);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 790);
-}
-
 #[test]
 fn part2_example() {
     let input = include_str!(concat!(
@@ -153,22 +142,3 @@ fn part2_example() {
         )
     );
 }
-
-#[test]
-fn part2_full() {
-    // PGHZBFJC
-    assert_eq!(
-        part2(INPUT),
-        String::from(
-            "\
-###...##..#..#.####.###..####...##..##.
-#..#.#..#.#..#....#.#..#.#.......#.#..#
-#..#.#....####...#..###..###.....#.#...
-###..#.##.#..#..#...#..#.#.......#.#...
-#....#..#.#..#.#....#..#.#....#..#.#..#
-#.....###.#..#.####.###..#.....##...##.
-\
-"
-        )
-    );
-}
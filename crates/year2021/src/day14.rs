@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::HashMap;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day14.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "14", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "14", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "14"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 #[derive(Debug)]
@@ -97,31 +100,3 @@ pub fn part1(input: &str) -> u64 {
 pub fn part2(input: &str) -> u64 {
     both(input, 40)
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day14.example.txt"
-    ));
-    assert_eq!(part1(input), 1749 - 161);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 2947);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day14.example.txt"
-    ));
-    assert_eq!(part2(input), 2188189693529);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 3232426226464);
-}
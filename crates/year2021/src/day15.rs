@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::{HashMap, HashSet};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day15.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "15", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "15", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "15"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 fn parse_input(input: &str) -> Vec<Vec<u8>> {
@@ -86,31 +89,3 @@ pub fn part2(input: &str) -> u32 {
     let map = parse_input(input);
     traverse(&map, 5)
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day15.example.txt"
-    ));
-    assert_eq!(part1(input), 40);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 562);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day15.example.txt"
-    ));
-    assert_eq!(part2(input), 315);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 2874);
-}
@@ -1,23 +1,26 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day16.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "16", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "16", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "16"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 fn parse_input(input: &str) -> impl Iterator<Item = bool> + '_ {
@@ -266,11 +269,6 @@ fn part1_example4() {
     assert_eq!(part1(input), 31);
 }
 
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 986);
-}
-
 #[test]
 fn part2_example_2() {
     let input = "C200B40A82";
@@ -319,8 +317,3 @@ fn part2_example5() {
     let result = (1 + 3 == 2 * 2) as usize;
     assert_eq!(part2(input), result);
 }
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 18234816469452);
-}
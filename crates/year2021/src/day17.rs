@@ -1,25 +1,28 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::HashSet;
 use std::ops::RangeInclusive;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day17.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "17", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "17", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "17"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 #[derive(Clone, Debug)]
@@ -150,7 +153,8 @@ fn sanity_example() {
 }
 #[test]
 fn sanity_full() {
-    let target = parse_input(INPUT);
+    let input = helper::personal_input_or_skip!(2021, 17);
+    let target = parse_input(&input);
     let possible = possible_vectors(target.clone());
     assert!(
         possible
@@ -158,31 +162,3 @@ fn sanity_full() {
             .all(|elem| simulate(target.clone(), elem))
     )
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day17.example.txt"
-    ));
-    assert_eq!(part1(input), 45);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 2701);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day17.example.txt"
-    ));
-    assert_eq!(part2(input), 112);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1070);
-}
@@ -1,26 +1,29 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::iter::Sum;
 use std::ops::{Add, ControlFlow};
 use std::str::FromStr;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day18.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "18", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "18", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "18"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 #[derive(Eq, PartialEq, Debug, Clone)]
@@ -505,31 +508,3 @@ fn magnitude_examples() {
         3488
     );
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day18.example5.txt"
-    ));
-    assert_eq!(part1(input), 4140);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 4124);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day18.example5.txt"
-    ));
-    assert_eq!(part2(input), 3993);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 4673);
-}
@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Tag, Task};
 use linkme::distributed_slice;
 use std::collections::HashSet;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day19.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "19", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: false,
+    run: |input| part1(input).into(),
+    tags: &[Tag::Slow],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "19", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: false,
+    run: |input| part2(input).into(),
+    tags: &[Tag::Slow],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "19"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 type Matrix<const N: usize, const M: usize> = [[isize; M]; N];
@@ -269,31 +272,3 @@ pub fn part2(input: &str) -> usize {
 pub fn manhattan_distance(a: &Vector<3>, b: &Vector<3>) -> usize {
     ((a[0] - b[0]).abs() + (a[1] - b[1]).abs() + (a[2] - b[2]).abs()) as usize
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day19.example.txt"
-    ));
-    assert_eq!(part1(input), 79);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 362);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day19.example.txt"
-    ));
-    assert_eq!(part2(input), 3621);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 12204);
-}
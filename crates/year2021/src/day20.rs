@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::fmt::{Formatter, Write};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day20.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "20", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "20", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "20"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 pub struct Input {
@@ -127,31 +130,3 @@ pub fn part1(input: &str) -> usize {
 pub fn part2(input: &str) -> usize {
     enhance(parse_input(input), 50)
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day20.example.txt"
-    ));
-    assert_eq!(part1(input), 35);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 5400);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day20.example.txt"
-    ));
-    assert_eq!(part2(input), 3351);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 18989);
-}
@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::HashMap;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day21.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "21", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "21", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "21"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 #[derive(Hash, Eq, PartialEq, Clone)]
@@ -127,31 +130,3 @@ pub fn part2(input: &str) -> u64 {
     let (wins_a, wins_b) = split_the_timeline(&a, &b, &mut cache);
     wins_a.max(wins_b)
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day21.example.txt"
-    ));
-    assert_eq!(part1(input), 745 * 993);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 734820);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day21.example.txt"
-    ));
-    assert_eq!(part2(input), 444356092776315);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 193170338541590);
-}
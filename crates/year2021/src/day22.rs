@@ -1,26 +1,29 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::num::ParseIntError;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day22.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "22", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "22", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "22"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 #[derive(Debug)]
@@ -224,31 +227,3 @@ pub fn part2(input: &str) -> usize {
 
     on.into_iter().map(|elem| elem.size()).sum()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day22.example1.txt"
-    ));
-    assert_eq!(part1(input), 590784);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 580012);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day22.example2.txt"
-    ));
-    assert_eq!(part2(input), 2758514936282235);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1334238660555542);
-}
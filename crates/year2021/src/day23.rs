@@ -1,26 +1,29 @@
 use crate::day23::Crab::*;
 use crate::day23::HallwaySpot::*;
 use crate::day23::Move::{FromRoom, ToRoom};
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Tag, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day23.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "23", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: false,
+    run: |input| part1(input).into(),
+    tags: &[Tag::Slow],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "23", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: false,
+    run: |input| part2(input).into(),
+    tags: &[Tag::Slow],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "23"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 #[derive(Debug)]
@@ -452,12 +455,6 @@ fn part1_example() {
     assert_eq!(part1(input), 12521);
 }
 
-#[test]
-#[ignore = "extremely slow"]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 18195);
-}
-
 #[test]
 #[ignore = "extremely slow"]
 fn part2_example() {
@@ -467,17 +464,3 @@ fn part2_example() {
     ));
     assert_eq!(part2(input), 44169);
 }
-
-#[test]
-#[ignore = "extremely slow"]
-fn part2_full() {
-    // #############
-    // #...........#
-    // ###A#C#B#A###
-    //   #D#C#B#A#
-    //   #D#B#A#C#
-    //   #D#D#B#C#
-    //   #########
-
-    assert_eq!(part2(INPUT), 50265);
-}
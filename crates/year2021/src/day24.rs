@@ -2,35 +2,33 @@ use crate::day24::Arg2::Literal;
 use crate::day24::Operation::{Add, Div, Eq, Inp, Mod, Mul};
 use crate::day24::Register::*;
 use RegisterState::{Input, Value};
-use helper::{TASKS, Task};
+use helper::{Answer, TASKS, Tag, Task};
 use linkme::distributed_slice;
 use std::fmt::{Display, Formatter};
 use std::rc::{Rc, Weak};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day24.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "24", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2021", "24", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static CODE: Task = Task {
     path: &["2021", "24", "code"],
-    run: || println!("{}", part1_instructions_to_code(INPUT)),
-    include_in_all: true,
+    run: |input| Answer::Art(part1_instructions_to_code(input)),
+    tags: &[Tag::Debug],
+    params: &[],
 };
 
 #[derive(Clone)]
@@ -658,6 +656,8 @@ fn equivalent() {
     assert_eq!(part1_fn(digits), part1_fn2(digits))
 }
 
+// the solution is specialized to one input and doesn't read it,
+// so unlike the other full tests these also run without the personal inputs
 #[test]
 fn part1_full() {
     assert_eq!(part1(""), 12996997829399);
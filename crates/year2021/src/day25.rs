@@ -1,17 +1,19 @@
 use crate::day25::Spot::{Down, Empty, Right};
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2021/day25.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2021", "25", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2021", "25"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 enum Spot {
@@ -112,17 +114,3 @@ pub fn part1(input: &str) -> u32 {
 
     iterations
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2021/day25.example.txt"
-    ));
-    assert_eq!(part1(input), 58);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 509);
-}
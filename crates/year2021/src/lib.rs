@@ -1,3 +1,22 @@
+/// the tasks too slow to check on every test run
+#[cfg(test)]
+const SLOW: &[&[&str]] = &[&["2021", "23"]];
+
+#[test]
+fn known_answers() {
+    helper::answers::assert_year_answers(2021, |task| {
+        !SLOW.iter().any(|slow| task.path.starts_with(slow))
+    });
+}
+
+#[test]
+#[ignore = "extremely slow"]
+fn slow_known_answers() {
+    helper::answers::assert_year_answers(2021, |task| {
+        SLOW.iter().any(|slow| task.path.starts_with(slow))
+    });
+}
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -1,23 +1,20 @@
 use helper::{TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day02.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "2", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "2", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[allow(clippy::upper_case_acronyms)]
@@ -100,31 +97,3 @@ pub fn part2(input: &str) -> u32 {
         .map(|(other, me)| me.result_reward(&other))
         .sum()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day02.example.txt"
-    ));
-    assert_eq!(part1(input), 15);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 10941);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day02.example.txt"
-    ));
-    assert_eq!(part2(input), 12);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 13071);
-}
@@ -2,23 +2,20 @@ use helper::{TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::BTreeSet;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day03.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "3", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "3", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 fn calculate_priority(item: u8) -> u8 {
     if item.is_ascii_lowercase() {
@@ -72,31 +69,3 @@ pub fn part2(input: &str) -> u32 {
         })
         .sum()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day03.example.txt"
-    ));
-    assert_eq!(part1(input), 16 + 38 + 42 + 22 + 20 + 19);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 7785);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day03.example.txt"
-    ));
-    assert_eq!(part2(input), 18 + 52);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 2633);
-}
@@ -2,23 +2,20 @@ use helper::{TASKS, Task};
 use linkme::distributed_slice;
 use std::ops::RangeInclusive;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day04.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "4", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "4", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 fn parse_range(input: &str) -> RangeInclusive<u32> {
@@ -55,31 +52,3 @@ pub fn part1(input: &str) -> usize {
 pub fn part2(input: &str) -> usize {
     both(input, |(l, r)| intersect(l, r))
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day04.example.txt"
-    ));
-    assert_eq!(part1(input), 2);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 542);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day04.example.txt"
-    ));
-    assert_eq!(part2(input), 4);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 900);
-}
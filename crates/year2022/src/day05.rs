@@ -1,23 +1,20 @@
 use helper::{TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day05.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "5", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "5", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[derive(PartialEq, Eq, Debug)]
@@ -147,31 +144,3 @@ pub fn part2(input: &str) -> String {
     input.apply_moves_9001();
     input.tops()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day05.example.txt"
-    ));
-    assert_eq!(part1(input).as_str(), "CMZ");
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT).as_str(), "NTWZZWHFV");
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day05.example.txt"
-    ));
-    assert_eq!(part2(input).as_str(), "MCD");
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT).as_str(), "BRZGFVBTJ");
-}
@@ -2,23 +2,20 @@ use helper::{TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::BTreeSet;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day06.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "6", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "6", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 pub fn first_distinct_run(input: &str, run: usize) -> Option<usize> {
@@ -54,19 +51,9 @@ fn part1_example() {
     }
 }
 
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 1909);
-}
-
 #[test]
 fn part2_example() {
     for (input, _, result_part2) in EXAMPLES {
         assert_eq!(part2(input), *result_part2);
     }
 }
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 3380);
-}
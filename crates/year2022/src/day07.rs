@@ -2,23 +2,20 @@ use helper::{TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::HashMap;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day07.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "7", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "7", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 enum DirOrFile<'a> {
@@ -137,31 +134,3 @@ pub fn part2(input: &str) -> usize {
 
     min
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day07.example.txt"
-    ));
-    assert_eq!(part1(input), 584 + 94853);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 2104783);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day07.example.txt"
-    ));
-    assert_eq!(part2(input), 24933642);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 5883165);
-}
@@ -2,23 +2,20 @@ use helper::{TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::HashSet;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day09.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "9", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "9", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -101,31 +98,3 @@ pub fn part1(input: &str) -> usize {
 pub fn part2(input: &str) -> usize {
     both(input, 10)
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day09.example.txt"
-    ));
-    assert_eq!(part1(input), 13);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 6337);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day09.example.txt"
-    ));
-    assert_eq!(part2(input), 1);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 2455);
-}
@@ -1,23 +1,20 @@
-use helper::{TASKS, Task};
+use helper::{Answer, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day10.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "10", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "10", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| Answer::Art(part2(input)),
+    tags: &[],
+    params: &[],
 };
 
 enum Instruction {
@@ -91,20 +88,6 @@ pub fn part2(input: &str) -> String {
     result
 }
 
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day10.example.txt"
-    ));
-    assert_eq!(part1(input), 420 + 1140 + 1800 + 2940 + 2880 + 3960);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 15360);
-}
-
 #[test]
 fn part2_example() {
     let input = include_str!(concat!(
@@ -124,17 +107,3 @@ fn part2_example() {
 "
     );
 }
-
-#[test]
-fn part2_full() {
-    assert_eq!(
-        part2(INPUT),
-        "\
-###..#..#.#....#..#...##..##..####..##..
-#..#.#..#.#....#..#....#.#..#....#.#..#.
-#..#.####.#....####....#.#......#..#..#.
-###..#..#.#....#..#....#.#.##..#...####.
-#....#..#.#....#..#.#..#.#..#.#....#..#.
-#....#..#.####.#..#..##...###.####.#..#.\n\n"
-    );
-}
@@ -2,23 +2,20 @@ use helper::{TASKS, Task};
 use linkme::distributed_slice;
 use std::{cell::RefCell, ops::DerefMut};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day11.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "11", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "11", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 struct Monkey {
     items: Vec<usize>,
@@ -164,31 +161,3 @@ pub fn part2(input: &str) -> u64 {
     let lcm: usize = monkeys.iter().map(|monkey| monkey.test).product();
     both(monkeys, 10_000, |x| x % lcm)
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day11.example.txt"
-    ));
-    assert_eq!(part1(input), 101 * 105);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 98280);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day11.example.txt"
-    ));
-    assert_eq!(part2(input), 52166 * 52013);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 17673687232);
-}
@@ -1,24 +1,28 @@
-use helper::{TASKS, Task};
+use helper::{TASKS, Task, VARIANTS, Variant};
 use linkme::distributed_slice;
 use std::{cell::Cell, collections::HashMap};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day12.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "12", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(VARIANTS)]
+static PART1_ALT: Variant = Variant {
+    path: &["2022", "12", "part1"],
+    name: "alt",
+    run: |input| part1_alt(input).into(),
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "12", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[derive(Debug)]
@@ -159,20 +163,6 @@ pub fn part2(input: &str) -> u32 {
     )
 }
 
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day12.example.txt"
-    ));
-    assert_eq!(part1(input), 31);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 339);
-}
-
 #[test]
 fn part1_alt_example() {
     let input = include_str!(concat!(
@@ -184,19 +174,6 @@ fn part1_alt_example() {
 
 #[test]
 fn part1_alt_full() {
-    assert_eq!(part1_alt(INPUT), 339);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day12.example.txt"
-    ));
-    assert_eq!(part2(input), 29);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 332);
+    let input = helper::personal_input_or_skip!(2022, 12);
+    assert_eq!(part1_alt(&input), 339);
 }
@@ -2,23 +2,20 @@ use helper::{TASKS, Task};
 use linkme::distributed_slice;
 use std::cmp::Ordering;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day13.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "13", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "13", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
@@ -330,31 +327,3 @@ fn parse_example() {
         assert_eq!(input[i], expected[i]);
     }
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day13.example.txt"
-    ));
-    assert_eq!(part1(input), 1 + 2 + 4 + 6);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 6235);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day13.example.txt"
-    ));
-    assert_eq!(part2(input), 10 * 14);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 22866);
-}
@@ -2,23 +2,20 @@ use helper::{TASKS, Task};
 use linkme::distributed_slice;
 use std::{collections::HashSet, ops::ControlFlow};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day14.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "14", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "14", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 struct Input {
@@ -104,31 +101,3 @@ pub fn part2(input: &str) -> u32 {
         ControlFlow::Break(_) => panic!("The floor should have stopped us"),
     }
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day14.example.txt"
-    ));
-    assert_eq!(part1(input), 24);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 832);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day14.example.txt"
-    ));
-    assert_eq!(part2(input), 93);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 27601);
-}
@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{Param, TASKS, Task, param};
 use linkme::distributed_slice;
 use std::{collections::HashSet, ops::RangeInclusive};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day15.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "15", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input, param("y")).into(),
+    tags: &[],
+    params: &[Param {
+        name: "y",
+        default: "2000000",
+    }],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "15", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input, param("max")).into(),
+    tags: &[],
+    params: &[Param {
+        name: "max",
+        default: "4000000",
+    }],
 };
 
 struct Sensor {
@@ -89,7 +92,7 @@ fn blocked_at_row(
     }
 }
 
-fn p1(input: &str, y: isize) -> usize {
+pub fn part1(input: &str, y: isize) -> usize {
     let pairs = parse(input);
     pairs
         .iter()
@@ -98,7 +101,7 @@ fn p1(input: &str, y: isize) -> usize {
         .len()
 }
 
-fn p2(input: &str, max: isize) -> usize {
+pub fn part2(input: &str, max: isize) -> usize {
     let pairs = parse(input);
 
     for y in 0..=max {
@@ -119,39 +122,3 @@ fn p2(input: &str, max: isize) -> usize {
     }
     panic!("We didn't find a match!")
 }
-
-pub fn part1(input: &str) -> usize {
-    p1(input, 2000000)
-}
-
-pub fn part2(input: &str) -> usize {
-    p2(input, 4000000)
-}
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day15.example.txt"
-    ));
-    assert_eq!(p1(input, 10), 26);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 5394423);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day15.example.txt"
-    ));
-    assert_eq!(p2(input, 20), 14 * 4000000 + 11);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 11840879211051);
-}
@@ -2,23 +2,20 @@ use helper::{TASKS, Task};
 use linkme::distributed_slice;
 use std::{collections::HashMap, ops::ControlFlow};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day17.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "17", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "17", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 type Position = (u8, u64);
@@ -274,31 +271,3 @@ pub fn part1(input: &str) -> u64 {
 pub fn part2(input: &str) -> u64 {
     both(input, 1_000_000_000_000)
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day17.example.txt"
-    ));
-    assert_eq!(part1(input), 3068);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 3085);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day17.example.txt"
-    ));
-    assert_eq!(part2(input), 1514285714288);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1535483870924);
-}
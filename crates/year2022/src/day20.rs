@@ -1,23 +1,20 @@
 use helper::{TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day20.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "20", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "20", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 fn parse(input: &str) -> Vec<isize> {
@@ -89,31 +86,3 @@ pub fn part2(input: &str) -> isize {
 
     x + y + z
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day20.example.txt"
-    ));
-    assert_eq!(part1(input), 4 + -3 + 2);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 4578);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day20.example.txt"
-    ));
-    assert_eq!(part2(input), 811589153 + 2434767459 + -1623178306);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 2159638736133);
-}
@@ -2,23 +2,20 @@ use helper::{TASKS, Task};
 use linkme::distributed_slice;
 use std::{collections::HashMap, rc::Rc};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day21.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "21", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "21", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 type Variable = str;
 pub enum Expression<'a> {
@@ -263,31 +260,3 @@ pub fn part2(input: &str) -> i64 {
         panic!("We lost the root state")
     }
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day21.example.txt"
-    ));
-    assert_eq!(part1(input), 152);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 54703080378102);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day21.example.txt"
-    ));
-    assert_eq!(part2(input), 301);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 3952673930912);
-}
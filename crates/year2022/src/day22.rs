@@ -7,23 +7,20 @@ use std::{
     rc::{Rc, Weak},
 };
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day22.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "22", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "22", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[derive(Debug)]
@@ -658,31 +655,3 @@ pub fn part2(input: &str) -> usize {
     let cube = CubeMap::determine_cube(&tile_kind_map);
     walk(&tile_kind_map, &instructions, WrapMode::Cube(cube))
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day22.example.txt"
-    ));
-    assert_eq!(part1(input), 6032);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 1428);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day22.example.txt"
-    ));
-    assert_eq!(part2(input), 5031);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 142380);
-}
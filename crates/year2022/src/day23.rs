@@ -2,23 +2,20 @@ use helper::{TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::{HashMap, HashSet, VecDeque};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day23.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "23", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "23", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[derive(Hash, PartialEq, Eq, Clone, Copy)]
@@ -225,31 +222,3 @@ pub fn part2(input: &str) -> usize {
     }
     panic!("Didn't stop moving!")
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day23.example.txt"
-    ));
-    assert_eq!(part1(input), 110);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 4068);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day23.example.txt"
-    ));
-    assert_eq!(part2(input), 20);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 968);
-}
@@ -5,23 +5,20 @@ use std::{
     fmt::{Display, Write},
 };
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day24.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "24", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2022", "24", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -284,40 +281,3 @@ pub fn part2(input: &str) -> usize {
 
     p1 + p2 + p3
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day24.example.txt"
-    ));
-    assert_eq!(part1(input), 10);
-}
-
-#[test]
-fn part1_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day24.example2.txt"
-    ));
-    assert_eq!(part1(input), 18);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 305);
-}
-
-#[test]
-fn part2_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2022/day24.example2.txt"
-    ));
-    assert_eq!(part2(input), 18 + 23 + 13);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 305 + 284 + 316);
-}
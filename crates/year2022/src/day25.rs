@@ -5,16 +5,12 @@ use std::{
     str::FromStr,
 };
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2022/day25.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2022", "25", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).to_string().into(),
+    tags: &[],
+    params: &[],
 };
 
 #[derive(Debug, PartialEq, Eq)]
@@ -204,8 +200,3 @@ fn part1_example() {
     ));
     assert_eq!(part1(input).to_string().as_str(), "2=-1=0");
 }
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT).to_string().as_str(), "20-=0=02=-21=00-02=2");
-}
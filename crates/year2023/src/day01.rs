@@ -1,23 +1,26 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day01.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "1", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "1", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "1"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 fn parse_input(input: &str) -> impl Iterator<Item = &str> + '_ {
@@ -85,31 +88,3 @@ fn find_numbers(elem: &str) -> (u32, u32) {
 fn problem_case() {
     assert_eq!(find_numbers("fpfqp7three7"), (7, 7))
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day01.example1.txt"
-    ));
-    assert_eq!(part1(input), 12 + 38 + 15 + 77);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 54159);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day01.example2.txt"
-    ));
-    assert_eq!(part2(input), 29 + 83 + 13 + 24 + 42 + 14 + 76);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 53866);
-}
@@ -1,25 +1,28 @@
 use helper::IntegerExtension;
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::HashMap;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day03.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "3", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "3", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "3"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -165,31 +168,3 @@ pub fn part2(input: &str) -> usize {
         })
         .sum()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day03.example.txt"
-    ));
-    assert_eq!(part1(input), 4361);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 525181);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day03.example.txt"
-    ));
-    assert_eq!(part2(input), 467835);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 84289137);
-}
@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::{HashSet, VecDeque};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day04.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "4", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "4", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "4"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 struct ScratchCard {
@@ -74,31 +77,3 @@ pub fn part2(input: &str) -> u32 {
         })
         .sum()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day04.example.txt"
-    ));
-    assert_eq!(part1(input), 13);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 23941);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day04.example.txt"
-    ));
-    assert_eq!(part2(input), 30);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 5571760);
-}
@@ -1,25 +1,29 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::{collections::HashMap, ops::RangeInclusive};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day05.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "5", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "5", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "5"],
+    run: |input| helper::consume(parse_input(input)),
 };
+
 #[derive(Debug)]
 struct Mapping {
     dest_start: usize,
@@ -100,7 +104,7 @@ impl Mappings {
                         .flatten()
                         .filter(|elem| !elem.is_empty()),
                 );
-                result.extend(mapped.into_iter());
+                result.extend(mapped);
             }
         }
 
@@ -222,31 +226,3 @@ pub fn part2(input: &str) -> usize {
         .min()
         .unwrap()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day05.example.txt"
-    ));
-    assert_eq!(part1(input), 35);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 403695602);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day05.example.txt"
-    ));
-    assert_eq!(part2(input), 46);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 219529182);
-}
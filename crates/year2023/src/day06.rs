@@ -1,24 +1,27 @@
 use helper::IntegerExtension;
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day06.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "6", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "6", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "6"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 #[derive(Debug)]
@@ -79,31 +82,3 @@ pub fn part2(input: &str) -> usize {
     );
     race.winning_holdings()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day06.example.txt"
-    ));
-    assert_eq!(part1(input), 4 * 8 * 9);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 771628);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day06.example.txt"
-    ));
-    assert_eq!(part2(input), 71503);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 27363861);
-}
@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::{collections::BTreeMap, str::FromStr};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day07.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "7", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "7", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "7"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
@@ -319,31 +322,3 @@ pub fn part2(input: &str) -> usize {
         .map(|(idx, bet)| (idx + 1) * bet.amount as usize)
         .sum()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day07.example.txt"
-    ));
-    assert_eq!(part1(input), 765 * 1 + 220 * 2 + 28 * 3 + 684 * 4 + 483 * 5);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 248396258);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day07.example.txt"
-    ));
-    assert_eq!(part2(input), 5905);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 246436046);
-}
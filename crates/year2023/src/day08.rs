@@ -1,27 +1,30 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Tag, Task};
 use linkme::distributed_slice;
 use std::{
     borrow::Borrow,
     collections::{BTreeSet, HashMap},
 };
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day08.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "8", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "8", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: false,
+    run: |input| part2(input).into(),
+    tags: &[Tag::Slow],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "8"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 #[derive(Debug)]
@@ -236,41 +239,3 @@ pub fn part2(input: &str) -> usize {
 
     cycle.first()
 }
-
-#[test]
-fn part1_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day08.example1.txt"
-    ));
-    assert_eq!(part1(input), 2);
-}
-
-#[test]
-fn part1_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day08.example2.txt"
-    ));
-    assert_eq!(part1(input), 6);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 13939);
-}
-
-#[test]
-fn part2_example3() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day08.example3.txt"
-    ));
-    assert_eq!(part2(input), 6);
-}
-
-#[test]
-#[ignore = "too slow"]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 8906539031197);
-}
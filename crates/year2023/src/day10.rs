@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::{HashSet, VecDeque};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day10.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "10", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "10", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "10"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 #[derive(Debug, Clone)]
@@ -311,67 +314,3 @@ pub fn part2(input: &str) -> usize {
         panic!("Ambiguous!")
     }
 }
-
-#[test]
-fn part1_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day10.example1.txt"
-    ));
-    assert_eq!(part1(input), 4);
-}
-
-#[test]
-fn part1_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day10.example2.txt"
-    ));
-    assert_eq!(part1(input), 8);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 6979);
-}
-
-#[test]
-fn part2_example3() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day10.example3.txt"
-    ));
-    assert_eq!(part2(input), 4);
-}
-
-#[test]
-fn part2_example4() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day10.example4.txt"
-    ));
-    assert_eq!(part2(input), 4);
-}
-
-#[test]
-fn part2_example5() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day10.example5.txt"
-    ));
-    assert_eq!(part2(input), 8);
-}
-
-#[test]
-fn part2_example6() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day10.example6.txt"
-    ));
-    assert_eq!(part2(input), 10);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 443);
-}
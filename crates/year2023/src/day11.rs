@@ -1,23 +1,26 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day11.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "11", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "11", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "11"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 struct Galaxy {
@@ -96,20 +99,6 @@ pub fn part2(input: &str) -> usize {
     distances(&galaxies)
 }
 
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day11.example.txt"
-    ));
-    assert_eq!(part1(input), 374);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 9177603);
-}
-
 #[test]
 fn part2_example1() {
     let input = include_str!(concat!(
@@ -135,8 +124,3 @@ fn part2_example2() {
 
     assert_eq!(distances(&galaxies), 8410);
 }
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 632003913611);
-}
@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::{collections::HashMap, str::FromStr};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day12.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "12", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "12", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "12"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -227,31 +230,3 @@ pub fn part2(input: &str) -> usize {
         })
         .sum()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day12.example.txt"
-    ));
-    assert_eq!(part1(input), 1 + 4 + 1 + 1 + 4 + 10);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 6827);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day12.example.txt"
-    ));
-    assert_eq!(part2(input), 1 + 16384 + 1 + 16 + 2500 + 506250);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1537505634471);
-}
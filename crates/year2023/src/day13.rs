@@ -1,25 +1,29 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::HashSet;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day13.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "13", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "13", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "13"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
+
 enum Axis {
     Row,
     Column,
@@ -196,31 +200,3 @@ pub fn part2(input: &str) -> usize {
         .map(|mirror| mirror.value())
         .sum()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day13.example.txt"
-    ));
-    assert_eq!(part1(input), 405);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 27742);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day13.example.txt"
-    ));
-    assert_eq!(part2(input), 400);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 32728);
-}
@@ -1,25 +1,35 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::{collections::VecDeque, num::Wrapping};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day15.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "15", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "15", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER1: Parser = Parser {
+    path: &["2023", "15", "part1"],
+    run: |input| parse_input1(input.lines().next().unwrap()).for_each(helper::consume),
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER2: Parser = Parser {
+    path: &["2023", "15", "part2"],
+    run: |input| parse_input2(input).for_each(helper::consume),
 };
+
 struct Instruction<'a> {
     label: &'a str,
     kind: InstructionKind,
@@ -128,31 +138,3 @@ pub fn part2(input: &str) -> usize {
         })
         .sum()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day15.example.txt"
-    ));
-    assert_eq!(part1(input), 1320);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 518107);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day15.example.txt"
-    ));
-    assert_eq!(part2(input), 145);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 303404);
-}
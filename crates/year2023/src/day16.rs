@@ -1,25 +1,29 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::{HashMap, HashSet};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day16.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "16", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "16", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "16"],
+    run: |input| helper::consume(parse_input(input)),
 };
+
 enum Obstacle {
     VerticalSplitter,
     HorizontalSplitter,
@@ -244,31 +248,3 @@ pub fn part2(input: &str) -> usize {
         .max()
         .unwrap()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day16.example.txt"
-    ));
-    assert_eq!(part1(input), 46);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 6816);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day16.example.txt"
-    ));
-    assert_eq!(part2(input), 51);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 8163);
-}
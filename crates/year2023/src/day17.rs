@@ -1,25 +1,29 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::{BTreeMap, HashSet};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day17.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "17", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "17", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "17"],
+    run: |input| helper::consume(parse_input(input)),
 };
+
 struct Input {
     heat_loss: Vec<Vec<u8>>,
 }
@@ -175,40 +179,3 @@ pub fn part1(input: &str) -> usize {
 pub fn part2(input: &str) -> usize {
     parse_input(input).find_path(true)
 }
-
-#[test]
-fn part1_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day17.example1.txt"
-    ));
-    assert_eq!(part1(input), 102);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 861);
-}
-
-#[test]
-fn part2_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day17.example1.txt"
-    ));
-    assert_eq!(part2(input), 94);
-}
-
-#[test]
-fn part2_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day17.example2.txt"
-    ));
-    assert_eq!(part2(input), 71);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1037);
-}
@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Tag, Task};
 use linkme::distributed_slice;
 use std::collections::BTreeMap;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day18.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "18", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "18", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[Tag::Slow],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "18"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -230,32 +233,3 @@ fn determine_border(
 
     border
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day18.example.txt"
-    ));
-    assert_eq!(part1(input), 62);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 34329);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day18.example.txt"
-    ));
-    assert_eq!(part2(input), 952408144115);
-}
-
-#[test]
-#[ignore = "too slow"]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 42617947302920);
-}
@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::{collections::HashMap, ops::RangeInclusive};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day19.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "19", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "19", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "19"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 enum Action<'s> {
@@ -333,31 +336,3 @@ pub fn part2(input: &str) -> u64 {
     let input = parse_input(input);
     input.acceptable()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day19.example.txt"
-    ));
-    assert_eq!(part1(input), 19114);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 480738);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day19.example.txt"
-    ));
-    assert_eq!(part2(input), 167409079868000);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 131550418841958);
-}
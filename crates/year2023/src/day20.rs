@@ -1,31 +1,38 @@
-use helper::{TASKS, Task};
+use helper::{Answer, PARSERS, Parser, TASKS, Tag, Task};
 use linkme::distributed_slice;
 use std::collections::{BTreeMap, HashSet, VecDeque};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day20.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "20", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "20", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static GRAPH: Task = Task {
     path: &["2023", "20", "graph"],
-    run: || print_graph(INPUT),
-    include_in_all: true,
+    run: |input| {
+        print_graph(input);
+        Answer::Unit
+    },
+    tags: &[Tag::Visualization],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "20"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -268,34 +275,6 @@ pub fn part2(input: &str) -> u64 {
         .fold(1, |acc, (_, cur)| helper::lcm(acc, cur))
 }
 
-#[test]
-fn part1_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day20.example1.txt"
-    ));
-    assert_eq!(part1(input), 8000 * 4000);
-}
-
-#[test]
-fn part1_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day20.example2.txt"
-    ));
-    assert_eq!(part1(input), 4250 * 2750);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 869395600);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 232605773145467);
-}
-
 pub fn print_graph(input: &str) {
     use std::fmt::Write;
 
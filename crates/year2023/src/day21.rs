@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::{HashMap, HashSet};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day21.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "21", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "21", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "21"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -210,11 +213,6 @@ fn part1_example() {
     assert_eq!(input.reachable_in_exactly(6), 16);
 }
 
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 3687);
-}
-
 #[test]
 #[ignore = "slow"]
 fn part2_example() {
@@ -239,8 +237,3 @@ fn part2_example() {
         assert_eq!(input.reachable_in_exactly(steps), reachable);
     }
 }
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 610321885082978);
-}
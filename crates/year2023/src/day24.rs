@@ -1,23 +1,26 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2023/day24.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2023", "24", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2023", "24", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2023", "24"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 #[derive(Debug)]
@@ -430,21 +433,6 @@ fn part1_example() {
     assert_eq!(result, 2);
 }
 
-#[test]
-fn part1_full() {
-    let result = part1(INPUT);
-    assert_eq!(result, 15107);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2023/day24.example.txt"
-    ));
-    assert_eq!(part2(input), 24 + 13 + 10);
-}
-
 #[test]
 fn part2_example2() {
     let input = include_str!(concat!(
@@ -455,8 +443,3 @@ fn part2_example2() {
 
     assert!(check_velocity([-3, 1, 2], &hail).is_some())
 }
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 856642398547748);
-}
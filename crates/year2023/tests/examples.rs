@@ -0,0 +1,5 @@
+use aoc2023 as _;
+
+fn main() {
+    helper::examples::test_examples(2023);
+}
@@ -1,23 +1,26 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day03.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "3", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "3", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "3"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 #[derive(Debug)]
@@ -79,31 +82,3 @@ pub fn part2(input: &str) -> u32 {
         }
     })
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day03.example1.txt"
-    ));
-    assert_eq!(part1(input), 2 * 4 + 5 * 5 + 11 * 8 + 8 * 5);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 178886550);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day03.example2.txt"
-    ));
-    assert_eq!(part2(input), 2 * 4 + 8 * 5);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 87163705);
-}
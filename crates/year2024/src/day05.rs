@@ -1,25 +1,29 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::{HashMap, HashSet};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day05.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "5", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "5", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "5"],
+    run: |input| helper::consume(parse_input(input)),
 };
+
 struct Input {
     rules: HashMap<u8, HashSet<u8>>,
     updates: Vec<Vec<u8>>,
@@ -112,31 +116,3 @@ fn fixup(update: &mut [u8], rules: &HashMap<u8, HashSet<u8>>) {
         len -= 1;
     }
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day05.example.txt"
-    ));
-    assert_eq!(part1(input), 61 + 53 + 29);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 6267);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day05.example.txt"
-    ));
-    assert_eq!(part2(input), 47 + 29 + 47);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 5184);
-}
@@ -1,4 +1,4 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::{
     cell::Cell,
@@ -6,23 +6,26 @@ use std::{
     ops::ControlFlow,
 };
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day06.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "6", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "6", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "6"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 enum Tile {
@@ -162,31 +165,3 @@ pub fn part2(input: &str) -> usize {
         })
         .count()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day06.example.txt"
-    ));
-    assert_eq!(part1(input), 41);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 4515);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day06.example.txt"
-    ));
-    assert_eq!(part2(input), 6);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1309);
-}
@@ -1,26 +1,29 @@
 use std::collections::HashSet;
 
 use helper::IntegerExtension;
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day07.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "7", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "7", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "7"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 struct Test {
@@ -87,31 +90,3 @@ fn edge_case() {
     assert!(!test.is_possible(part1_ops));
     assert!(!test.is_possible(part2_ops));
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day07.example.txt"
-    ));
-    assert_eq!(part1(input), 3749);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 28730327770375);
-}
-
-#[test]
-fn part2_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day07.example.txt"
-    ));
-    assert_eq!(part2(input), 11387);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 424977609625985);
-}
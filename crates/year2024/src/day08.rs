@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::{HashMap, HashSet};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day08.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "8", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "8", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "8"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 type Position = (isize, isize);
@@ -111,40 +114,3 @@ pub fn part2(input: &str) -> usize {
         .collect::<HashSet<_>>();
     anti_nodes.len()
 }
-
-#[test]
-fn part1_example() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day08.example1.txt"
-    ));
-    assert_eq!(part1(input), 14);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 371);
-}
-
-#[test]
-fn part2_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day08.example1.txt"
-    ));
-    assert_eq!(part2(input), 34);
-}
-
-#[test]
-fn part2_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day08.example2.txt"
-    ));
-    assert_eq!(part2(input), 9);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1229);
-}
@@ -1,24 +1,27 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use std::collections::HashMap;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day10.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "10", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "10", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "10"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 type Pos = (isize, isize);
@@ -85,14 +88,6 @@ pub fn part2(input: &str) -> usize {
         .sum()
 }
 
-#[test]
-fn part1_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day10.example1.txt"
-    ));
-    assert_eq!(part1(input), 5 + 6 + 5 + 3 + 1 + 3 + 5 + 3 + 5);
-}
 #[test]
 fn part1_example2() {
     let input = include_str!(concat!(
@@ -101,22 +96,3 @@ fn part1_example2() {
     ));
     assert_eq!(part1(input), 2);
 }
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 682);
-}
-
-#[test]
-fn part2_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day10.example1.txt"
-    ));
-    assert_eq!(part2(input), 20 + 24 + 10 + 4 + 1 + 4 + 5 + 8 + 5);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1511);
-}
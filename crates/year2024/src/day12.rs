@@ -1,26 +1,29 @@
 use std::collections::{BTreeMap, BTreeSet, btree_map::Entry};
 
 type Pos = (isize, isize);
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day12.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "12", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "12", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "12"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 fn parse_input(input: &str) -> BTreeMap<Pos, char> {
@@ -137,24 +140,6 @@ fn both(input: &str, length: fn(&BTreeSet<Pos>) -> usize) -> usize {
         .sum()
 }
 
-#[test]
-fn part1_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day12.example1.txt"
-    ));
-    assert_eq!(part1(input), 4 * 10 + 4 * 8 + 4 * 10 + 1 * 4 + 3 * 8);
-}
-
-#[test]
-fn part1_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day12.example2.txt"
-    ));
-    assert_eq!(part1(input), 21 * 36 + 1 * 4 * 4);
-}
-
 #[test]
 fn part1_example3() {
     let input = include_str!(concat!(
@@ -176,58 +161,3 @@ fn part1_example3() {
             + 3 * 8
     );
 }
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 1361494);
-}
-
-#[test]
-fn part2_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day12.example1.txt"
-    ));
-    assert_eq!(part2(input), 16 + 16 + 32 + 4 + 12);
-}
-
-#[test]
-fn part2_example2() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day12.example2.txt"
-    ));
-    assert_eq!(part2(input), 436);
-}
-
-#[test]
-fn part2_example3() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day12.example3.txt"
-    ));
-    assert_eq!(part2(input), 1206);
-}
-
-#[test]
-fn part2_example4() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day12.example4.txt"
-    ));
-    assert_eq!(part2(input), 236);
-}
-
-#[test]
-fn part2_example5() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day12.example5.txt"
-    ));
-    assert_eq!(part2(input), 368);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 830516);
-}
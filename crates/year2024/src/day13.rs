@@ -1,23 +1,33 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task, VARIANTS, Variant};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day13.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "13", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(VARIANTS)]
+static PART1_SIMPLE: Variant = Variant {
+    path: &["2024", "13", "part1"],
+    name: "simple",
+    run: |input| part1_simple(input).into(),
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "13", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "13"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 #[derive(Debug)]
@@ -109,6 +119,12 @@ pub fn part1(input: &str) -> usize {
         .sum()
 }
 
+pub fn part1_simple(input: &str) -> usize {
+    parse_input(input)
+        .flat_map(|game| optimal_game_simple(&game))
+        .sum()
+}
+
 pub fn part2(input: &str) -> usize {
     parse_input(input)
         .map(|mut game| {
@@ -120,20 +136,6 @@ pub fn part2(input: &str) -> usize {
         .sum()
 }
 
-#[test]
-fn part1_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day13.example1.txt"
-    ));
-    assert_eq!(part1(input), 480);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 26299);
-}
-
 #[test]
 fn part1_compare_example1() {
     let input = include_str!(concat!(
@@ -148,12 +150,12 @@ fn part1_compare_example1() {
 
 #[test]
 fn part1_compare_full() {
-    for game in parse_input(INPUT) {
+    let input = helper::personal_input_or_skip!(2024, 13);
+    for game in parse_input(&input) {
         assert_eq!(optimal_game(&game), optimal_game_simple(&game))
     }
 }
 
-#[cfg(test)]
 fn optimal_game_simple(game: &Game) -> Option<usize> {
     let max_bs = (game.prize.0 / game.delta_b.0).min(game.prize.1 / game.delta_b.1);
     for b in (0..=max_bs).rev() {
@@ -168,18 +170,3 @@ fn optimal_game_simple(game: &Game) -> Option<usize> {
     }
     None
 }
-
-#[test]
-fn part2_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day13.example1.txt"
-    ));
-    assert_eq!(part2(input), 875318608908);
-}
-
-#[test]
-fn part2_full() {
-    let result = part2(INPUT);
-    assert_eq!(result, 107824497933339);
-}
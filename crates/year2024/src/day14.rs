@@ -1,25 +1,50 @@
 use helper::lcm;
-use helper::{TASKS, Task};
+use helper::{PARSERS, Param, Parser, TASKS, Task, param};
 use linkme::distributed_slice;
 use std::{collections::BTreeSet, io::BufWriter};
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day14.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "14", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input, [param("width"), param("height")], param("steps")).into(),
+    tags: &[],
+    params: &[
+        Param {
+            name: "width",
+            default: "101",
+        },
+        Param {
+            name: "height",
+            default: "103",
+        },
+        Param {
+            name: "steps",
+            default: "100",
+        },
+    ],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "14", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input, [param("width"), param("height")]).into(),
+    tags: &[],
+    params: &[
+        Param {
+            name: "width",
+            default: "101",
+        },
+        Param {
+            name: "height",
+            default: "103",
+        },
+    ],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "14"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 #[derive(Debug)]
@@ -79,21 +104,13 @@ fn quadrant_occupation(robots: impl Iterator<Item = Robot>, dim: [isize; 2]) ->
     })
 }
 
-fn part1_impl(input: &str, dim: [isize; 2], steps: isize) -> u32 {
+pub fn part1(input: &str, dim: [isize; 2], steps: isize) -> u32 {
     quadrant_occupation(move_robots(parse_input(input), dim, steps), dim)
         .into_iter()
         .product()
 }
 
-pub fn part1(input: &str) -> u32 {
-    part1_impl(input, [101, 103], 100)
-}
-
-pub fn part2(input: &str) -> u32 {
-    part2_impl(input, [101, 103])
-}
-
-fn part2_impl(input: &str, dim: [isize; 2]) -> u32 {
+pub fn part2(input: &str, dim: [isize; 2]) -> u32 {
     use std::io::Write as _;
     let mut stream = if log::log_enabled!(log::Level::Debug) {
         Some(BufWriter::new(std::fs::File::create("trees.txt").unwrap()))
@@ -156,22 +173,3 @@ fn print_robots<W: std::io::Write>(robots: &[Robot], dim: [isize; 2], stream: &m
     }
     let _ = stream.write(b"\n");
 }
-
-#[test]
-fn part1_example1() {
-    let input = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../inputs/example/year2024/day14.example1.txt"
-    ));
-    assert_eq!(part1_impl(input, [11, 7], 100), 1 * 3 * 4 * 1);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 224438715);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 7603);
-}
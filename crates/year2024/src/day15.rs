@@ -1,37 +1,28 @@
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day15.txt"
-));
-
-#[cfg(test)]
-const INPUT_EXAMPLE1: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/example/year2024/day15.example1.txt"
-));
-
-#[cfg(test)]
-const INPUT_EXAMPLE2: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/example/year2024/day15.example2.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "15", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "15", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "15"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 enum SimleTiles {
@@ -341,28 +332,3 @@ pub fn part2(input: &str) -> isize {
     }
     gps_cost
 }
-
-#[test]
-fn part1_example1() {
-    assert_eq!(part1(INPUT_EXAMPLE1), 10092);
-}
-
-#[test]
-fn part1_example2() {
-    assert_eq!(part1(INPUT_EXAMPLE2), 2028);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 1563092);
-}
-
-#[test]
-fn part2_example1() {
-    assert_eq!(part2(INPUT_EXAMPLE1), 9021);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1582688);
-}
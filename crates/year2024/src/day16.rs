@@ -3,38 +3,29 @@ use std::{
     collections::{BTreeMap, BTreeSet},
 };
 
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day16.txt"
-));
-
-#[cfg(test)]
-const INPUT_EXAMPLE1: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/example/year2024/day16.example1.txt"
-));
-
-#[cfg(test)]
-const INPUT_EXAMPLE2: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/example/year2024/day16.example2.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "16", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "16", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "16"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 struct Input {
@@ -208,33 +199,3 @@ pub fn part2(input: &str) -> usize {
     let input = parse_input(input);
     dijkstra(&input.map, input.start, input.end).1.len()
 }
-
-#[test]
-fn part1_example1() {
-    assert_eq!(part1(INPUT_EXAMPLE1), 7036);
-}
-
-#[test]
-fn part1_example2() {
-    assert_eq!(part1(INPUT_EXAMPLE2), 11048);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 115500);
-}
-
-#[test]
-fn part2_example1() {
-    assert_eq!(part2(INPUT_EXAMPLE1), 45);
-}
-
-#[test]
-fn part2_example2() {
-    assert_eq!(part2(INPUT_EXAMPLE2), 64);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 679);
-}
@@ -1,17 +1,6 @@
-use helper::{TASKS, Task};
+use helper::{Answer, PARSERS, Parser, TASKS, Tag, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day17.txt"
-));
-
-#[cfg(test)]
-const INPUT_EXAMPLE1: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/example/year2024/day17.example1.txt"
-));
-
 const INPUT_EXAMPLE2: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
     "/../../inputs/example/year2024/day17.example2.txt"
@@ -20,29 +9,45 @@ const INPUT_EXAMPLE2: &str = include_str!(concat!(
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "17", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "17", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static CODE: Task = Task {
     path: &["2024", "17", "code"],
-    run: || print_code(INPUT),
-    include_in_all: true,
+    run: |input| {
+        print_code(input);
+        Answer::Unit
+    },
+    tags: &[Tag::Debug],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static CODE2: Task = Task {
     path: &["2024", "17", "code_example2"],
-    run: || print_code(INPUT_EXAMPLE2),
-    include_in_all: true,
+    run: |_| {
+        print_code(INPUT_EXAMPLE2);
+        Answer::Unit
+    },
+    tags: &[Tag::Debug],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "17"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 #[derive(Debug)]
@@ -415,23 +420,3 @@ pub fn find_a(remaining: &[u8], a: u64, program: &[u8]) -> Option<u64> {
         }
     }
 }
-
-#[test]
-fn part1_example1() {
-    assert_eq!(part1(INPUT_EXAMPLE1), "4,6,3,5,6,3,5,2,1,0");
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), "1,4,6,1,6,4,3,0,3");
-}
-
-#[test]
-fn part2_example2() {
-    assert_eq!(part2(INPUT_EXAMPLE2), 117440);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 265061364597659);
-}
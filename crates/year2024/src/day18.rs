@@ -1,13 +1,8 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day18.txt"
-));
-
 #[cfg(test)]
 const INPUT_EXAMPLE1: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
@@ -17,18 +12,26 @@ const INPUT_EXAMPLE1: &str = include_str!(concat!(
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "18", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "18", "part2"],
-    run: || {
-        let [x, y] = part2(INPUT);
-        println!("{x},{y}")
+    run: |input| {
+        let [x, y] = part2(input);
+        format!("{x},{y}").into()
     },
-    include_in_all: true,
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "18"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 type Pos = [i8; 2];
@@ -128,18 +131,8 @@ fn part1_example1() {
     assert_eq!(part1_impl(&blocked, [6, 6], 12).unwrap().len() - 1, 22);
 }
 
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 334);
-}
-
 #[test]
 fn part2_example1() {
     let blocked = parse_input(INPUT_EXAMPLE1);
     assert_eq!(part2_impl(&blocked, [6, 6]), [6, 1]);
 }
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), [20, 12]);
-}
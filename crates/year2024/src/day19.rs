@@ -1,31 +1,28 @@
 use std::collections::BTreeMap;
 
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day19.txt"
-));
-
-#[cfg(test)]
-const INPUT_EXAMPLE1: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/example/year2024/day19.example1.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "19", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "19", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "19"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -105,23 +102,3 @@ pub fn part2(input: &str) -> usize {
         .map(|towl| towl_combinations(&input, towl, &mut cache))
         .sum()
 }
-
-#[test]
-fn part1_example1() {
-    assert_eq!(part1(INPUT_EXAMPLE1), 6);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 272);
-}
-
-#[test]
-fn part2_example1() {
-    assert_eq!(part2(INPUT_EXAMPLE1), 16);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1041529704688380);
-}
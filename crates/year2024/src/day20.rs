@@ -1,13 +1,8 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day20.txt"
-));
-
 #[cfg(test)]
 const INPUT_EXAMPLE1: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
@@ -17,15 +12,23 @@ const INPUT_EXAMPLE1: &str = include_str!(concat!(
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "20", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "20", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "20"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 enum Tile {
@@ -207,11 +210,6 @@ fn part1_example1() {
     );
 }
 
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 1490);
-}
-
 #[test]
 fn part2_example1() {
     let input = parse_input(INPUT_EXAMPLE1);
@@ -244,8 +242,3 @@ fn part2_example1() {
         ])
     );
 }
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1011325);
-}
@@ -4,32 +4,29 @@ use std::{
     vec,
 };
 
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day21.txt"
-));
-
-#[cfg(test)]
-const INPUT_EXAMPLE1: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/example/year2024/day21.example1.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "21", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "21", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "21"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -262,22 +259,8 @@ fn both(input: &str, move_robots: usize) -> u128 {
         .sum()
 }
 
-#[test]
-fn part1_example1() {
-    assert_eq!(part1(INPUT_EXAMPLE1), 126384);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 128962);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 159684145150108);
-}
-
 #[test]
 fn so_many_robots() {
-    assert_eq!(both(INPUT, 86), 221895079889046776830013740791852600572);
+    let input = helper::personal_input_or_skip!(2024, 21);
+    assert_eq!(both(&input, 86), 221895079889046776830013740791852600572);
 }
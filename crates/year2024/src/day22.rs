@@ -1,13 +1,8 @@
 use std::collections::BTreeMap;
 
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day22.txt"
-));
-
 #[cfg(test)]
 const INPUT_EXAMPLE1: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
@@ -23,15 +18,23 @@ const INPUT_EXAMPLE2: &str = include_str!(concat!(
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "22", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "22", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "22"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 struct SecretNumber {
@@ -135,22 +138,14 @@ fn part1_prng_demo() {
 fn part1_example1() {
     assert_eq!(
         part1(INPUT_EXAMPLE1),
-        [8685429, 4700978, 15273692, 8667524].into_iter().sum()
+        [8685429, 4700978, 15273692, 8667524]
+            .into_iter()
+            .sum::<usize>()
     );
 }
 
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 19150344884);
-}
-
 #[test]
 fn part2_example2() {
     // sequence = [-2, 1, -1, 3]
     assert_eq!(part2(INPUT_EXAMPLE2), 7 + 7 + 0 + 9);
 }
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 2121);
-}
@@ -1,31 +1,28 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2024/day23.txt"
-));
-
-#[cfg(test)]
-const INPUT_EXAMPLE1: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/example/year2024/day23.example1.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2024", "23", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2024", "23", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2024", "23"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 struct Input<'a> {
@@ -100,23 +97,3 @@ pub fn part2(input: &str) -> String {
     };
     largest.into_iter().collect::<Vec<_>>().join(",")
 }
-
-#[test]
-fn part1_example1() {
-    assert_eq!(part1(INPUT_EXAMPLE1), 7);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 1110);
-}
-
-#[test]
-fn part2_example1() {
-    assert_eq!(part2(INPUT_EXAMPLE1), "co,de,ka,ta");
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), "ej,hm,ks,ms,ns,rb,rq,sc,so,un,vb,vd,wd");
-}
@@ -0,0 +1,5 @@
+use aoc2024 as _;
+
+fn main() {
+    helper::examples::test_examples(2024);
+}
@@ -1,11 +1,6 @@
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2025/day03.txt"
-));
-
 #[cfg(test)]
 const INPUT_EXAMPLE1: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
@@ -15,15 +10,23 @@ const INPUT_EXAMPLE1: &str = include_str!(concat!(
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2025", "03", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2025", "03", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2025", "03"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 fn parse_input(input: &str) -> impl Iterator<Item = Vec<u8>> + '_ {
@@ -75,16 +78,6 @@ pub fn part2(input: &str) -> u128 {
     sum
 }
 
-#[test]
-fn part1_example1() {
-    assert_eq!(part1(INPUT_EXAMPLE1), 98 + 89 + 78 + 92);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 17311);
-}
-
 #[test]
 fn part2_example1() {
     assert_eq!(
@@ -92,8 +85,3 @@ fn part2_example1() {
         987654321111 + 811111111119 + 434234234278 + 888911112111
     );
 }
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 171419245422055);
-}
@@ -1,31 +1,28 @@
 use std::ops::RangeInclusive;
 
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2025/day05.txt"
-));
-
-#[cfg(test)]
-const INPUT_EXAMPLE1: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/example/year2025/day05.example1.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2025", "05", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2025", "05", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2025", "05"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 struct Input {
@@ -86,23 +83,3 @@ pub fn part2(input: &str) -> usize {
 fn overlapps(new_range: &RangeInclusive<u64>, range: &RangeInclusive<u64>) -> bool {
     new_range.start() <= range.end() && range.start() <= new_range.end()
 }
-
-#[test]
-fn part1_example1() {
-    assert_eq!(part1(INPUT_EXAMPLE1), 3);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 558);
-}
-
-#[test]
-fn part2_example1() {
-    assert_eq!(part2(INPUT_EXAMPLE1), 14);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 344813017450467);
-}
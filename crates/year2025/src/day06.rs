@@ -1,31 +1,34 @@
 use std::collections::VecDeque;
 
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2025/day06.txt"
-));
-
-#[cfg(test)]
-const INPUT_EXAMPLE1: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/example/year2025/day06.example1.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2025", "06", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2025", "06", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER1: Parser = Parser {
+    path: &["2025", "06", "part1"],
+    run: |input| helper::consume(parse_input1(input)),
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER2: Parser = Parser {
+    path: &["2025", "06", "part2"],
+    run: |input| helper::consume(parse_input2(input)),
 };
 
 #[derive(Debug)]
@@ -95,21 +98,18 @@ fn parse_input2(input: &str) -> Vec<Calc> {
 
             let mut args = vec![];
 
-            loop {
-                // auto-formatting of inputs stips trailing spaces and last column doesn't have a trailing space so fallback to space if input line doesn't have any more characters
-                let Some(arg) = argss
-                    .iter_mut()
-                    .map(|line| line.pop_front().unwrap_or(' '))
-                    .fold(None, |acc, next| {
-                        match (acc, next.to_digit(10).map(|d| d as u64)) {
-                            (None, None) => None,
-                            (None, Some(x)) | (Some(x), None) => Some(x),
-                            (Some(x), Some(y)) => Some(x * 10 + y),
-                        }
-                    })
-                else {
-                    break;
-                };
+            // auto-formatting of inputs stips trailing spaces and last column doesn't have a trailing space so fallback to space if input line doesn't have any more characters
+            while let Some(arg) = argss
+                .iter_mut()
+                .map(|line| line.pop_front().unwrap_or(' '))
+                .fold(None, |acc, next| {
+                    match (acc, next.to_digit(10).map(|d| d as u64)) {
+                        (None, None) => None,
+                        (None, Some(x)) | (Some(x), None) => Some(x),
+                        (Some(x), Some(y)) => Some(x * 10 + y),
+                    }
+                })
+            {
                 args.push(arg);
             }
 
@@ -128,23 +128,3 @@ pub fn part2(input: &str) -> u64 {
         .map(|calc| calc.eval())
         .sum()
 }
-
-#[test]
-fn part1_example1() {
-    assert_eq!(part1(INPUT_EXAMPLE1), 33210 + 490 + 4243455 + 401);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 5977759036837);
-}
-
-#[test]
-fn part2_example1() {
-    assert_eq!(part2(INPUT_EXAMPLE1), 1058 + 3253600 + 625 + 8544);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 9630000828442);
-}
@@ -1,31 +1,31 @@
 use std::collections::{BTreeMap, HashMap};
 
-use helper::{TASKS, Task};
+use helper::{PARSERS, Param, Parser, TASKS, Task, param};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2025/day08.txt"
-));
-
-#[cfg(test)]
-const INPUT_EXAMPLE1: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/example/year2025/day08.example1.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2025", "08", "part1"],
-    run: || println!("{}", part1(INPUT, 1000)),
-    include_in_all: true,
+    run: |input| part1(input, param("connections")).into(),
+    tags: &[],
+    params: &[Param {
+        name: "connections",
+        default: "1000",
+    }],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2025", "08", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2025", "08"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 fn parse_input(input: &str) -> impl Iterator<Item = [u32; 3]> + '_ {
@@ -122,23 +122,3 @@ pub fn part2(input: &str) -> u32 {
 
     last_pair.0[0] * last_pair.1[0]
 }
-
-#[test]
-fn part1_example1() {
-    assert_eq!(part1(INPUT_EXAMPLE1, 10), 5 * 4 * 2);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT, 1000), 50568);
-}
-
-#[test]
-fn part2_example1() {
-    assert_eq!(part2(INPUT_EXAMPLE1), 216 * 117);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 36045012);
-}
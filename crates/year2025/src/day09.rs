@@ -1,31 +1,28 @@
 use std::collections::BTreeSet;
 
-use helper::{InPoligon, TASKS, Task, point_in_polygon};
+use helper::{InPoligon, PARSERS, Parser, TASKS, Task, point_in_polygon};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2025/day09.txt"
-));
-
-#[cfg(test)]
-const INPUT_EXAMPLE1: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/example/year2025/day09.example1.txt"
-));
-
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2025", "09", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2025", "09", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2025", "09"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 fn parse_input(input: &str) -> impl Iterator<Item = [i32; 2]> + '_ {
@@ -38,7 +35,7 @@ fn parse_input(input: &str) -> impl Iterator<Item = [i32; 2]> + '_ {
 fn calculate_area(tile1: [i32; 2], tile2: [i32; 2]) -> u64 {
     tile1
         .into_iter()
-        .zip(tile2.into_iter())
+        .zip(tile2)
         .map(|(a, b)| (a.abs_diff(b) + 1) as u64)
         .product()
 }
@@ -159,23 +156,3 @@ pub fn part2(input: &str) -> u64 {
 
     unreachable!("No squares possible")
 }
-
-#[test]
-fn part1_example1() {
-    assert_eq!(part1(INPUT_EXAMPLE1), 50);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 4763509452);
-}
-
-#[test]
-fn part2_example1() {
-    assert_eq!(part2(INPUT_EXAMPLE1), 24);
-}
-
-#[test]
-fn part2_full() {
-    assert_eq!(part2(INPUT), 1516897893);
-}
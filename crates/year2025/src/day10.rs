@@ -1,14 +1,9 @@
 use std::{num::ParseIntError, str::FromStr};
 
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 use scryer_prolog::LeafAnswer;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2025/day10.txt"
-));
-
 #[cfg(test)]
 const INPUT_EXAMPLE1: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
@@ -18,15 +13,23 @@ const INPUT_EXAMPLE1: &str = include_str!(concat!(
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2025", "10", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
 };
 
 #[distributed_slice(TASKS)]
 static PART2: Task = Task {
     path: &["2025", "10", "part2"],
-    run: || println!("{}", part2(INPUT)),
-    include_in_all: true,
+    run: |input| part2(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2025", "10"],
+    run: |input| parse_input(input).for_each(helper::consume),
 };
 
 #[derive(Debug)]
@@ -147,16 +150,6 @@ fn build_query(machines: &[Machine]) -> String {
     format!("part2([\n\t\t{machines}\n\t], Res).")
 }
 
-#[test]
-fn part1_example1() {
-    assert_eq!(part1(INPUT_EXAMPLE1), 2 + 3 + 2);
-}
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 466);
-}
-
 #[test]
 fn part2_example1() {
     let query = build_query(&parse_input(INPUT_EXAMPLE1).collect::<Vec<_>>());
@@ -164,14 +157,3 @@ fn part2_example1() {
 
     assert_eq!(part2(INPUT_EXAMPLE1), 10 + 12 + 11);
 }
-
-#[test]
-fn part2_full() {
-    let query = build_query(&parse_input(INPUT).collect::<Vec<_>>());
-    let _ = std::fs::write("./day10-part2-full.pl", format!("test(Res) :- {query}"));
-
-    let res = part2(INPUT);
-
-    assert!(8788 < res);
-    assert_eq!(res, 17214);
-}
@@ -1,13 +1,8 @@
 use std::{collections::BTreeMap, num::ParseIntError, str::FromStr};
 
-use helper::{TASKS, Task};
+use helper::{PARSERS, Parser, TASKS, Task};
 use linkme::distributed_slice;
 
-const INPUT: &str = include_str!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../inputs/personal/year2025/day12.txt"
-));
-
 #[cfg(test)]
 const INPUT_EXAMPLE1: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
@@ -17,8 +12,15 @@ const INPUT_EXAMPLE1: &str = include_str!(concat!(
 #[distributed_slice(TASKS)]
 static PART1: Task = Task {
     path: &["2025", "12", "part1"],
-    run: || println!("{}", part1(INPUT)),
-    include_in_all: true,
+    run: |input| part1(input).into(),
+    tags: &[],
+    params: &[],
+};
+
+#[distributed_slice(PARSERS)]
+static PARSER: Parser = Parser {
+    path: &["2025", "12"],
+    run: |input| helper::consume(parse_input(input)),
 };
 
 struct Input {
@@ -30,7 +32,6 @@ struct Pattern {
     width: usize,
     heitgh: usize,
     units: usize,
-    pattern: Vec<Vec<bool>>,
 }
 
 struct Region {
@@ -108,7 +109,6 @@ fn parse_input(input: &str) -> Input {
                         .iter()
                         .map(|line| line.iter().copied().filter(|&e| e).count())
                         .sum(),
-                    pattern,
                 },
             )
         })
@@ -133,8 +133,3 @@ pub fn part1(input: &str) -> usize {
 fn part1_example1() {
     assert_eq!(part1(INPUT_EXAMPLE1), 2);
 }
-
-#[test]
-fn part1_full() {
-    assert_eq!(part1(INPUT), 587);
-}
@@ -0,0 +1,5 @@
+use aoc2025 as _;
+
+fn main() {
+    helper::examples::test_examples(2025);
+}